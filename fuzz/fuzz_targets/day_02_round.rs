@@ -0,0 +1,11 @@
+#![no_main]
+
+use advent_of_code_2022::day_02::Rules;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|line: &str| {
+    let rules = Rules::rock_paper_scissors();
+
+    let _ = rules.round(line);
+    let _ = rules.round_v2(line);
+});
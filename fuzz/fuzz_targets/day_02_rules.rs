@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code_2022::day_02::Rules;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|contents: &str| {
+    let _ = Rules::parse(contents);
+});
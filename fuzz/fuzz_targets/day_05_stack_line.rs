@@ -0,0 +1,9 @@
+#![no_main]
+
+use advent_of_code_2022::day_05::StackLineParser;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|line: &str| {
+    // The parser stops itself after an error, so this always ends
+    for _ in StackLineParser::new(line) {}
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code_2022::day_09::parse_line;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|line: &str| {
+    let _ = parse_line(line);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code_2022::day_10::Instr;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|line: &str| {
+    let _ = Instr::try_from(line);
+});
@@ -0,0 +1,20 @@
+#![no_main]
+
+use advent_of_code_2022::DAYS;
+use libfuzzer_sys::fuzz_target;
+
+// The first byte is the day, from 1, and the rest is its input
+fuzz_target!(|data: &[u8]| {
+    let Some((&day, input)) = data.split_first() else {
+        return;
+    };
+
+    let (Some(solver), Ok(input)) = (
+        DAYS.get((day as usize).wrapping_sub(1)),
+        std::str::from_utf8(input),
+    ) else {
+        return;
+    };
+
+    let _ = solver.solve(input, None);
+});
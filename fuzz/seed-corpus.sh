@@ -0,0 +1,58 @@
+#!/bin/sh
+# Fills fuzz/corpus with seeds for every target from the real inputs and the
+# examples: each distinct line for the line parsers, and each whole input
+# (after a byte for its day) for `solve`, along with the rules files for
+# `day_02_rules`.
+set -eu
+
+cd "$(dirname "$0")/.."
+
+corpus=fuzz/corpus
+
+# The real inputs and examples for day $1
+inputs() {
+    ls inputs/day_"$1".txt examples/day_"$1"/*.txt 2>/dev/null || true
+}
+
+# Seeds target $1 with every distinct line from day $2's inputs
+seed_lines() {
+    mkdir -p "$corpus/$1"
+
+    files=$(inputs "$2")
+    [ -n "$files" ] || return 0
+
+    # shellcheck disable=SC2086
+    sort -u $files | {
+        n=0
+        while IFS= read -r line; do
+            printf '%s' "$line" > "$corpus/$1/seed-$n"
+            n=$((n + 1))
+        done
+    }
+}
+
+seed_lines day_02_round 02
+seed_lines day_04_assignment_pair 04
+seed_lines day_05_instruction 05
+seed_lines day_05_stack_line 05
+seed_lines day_07_output_line 07
+seed_lines day_09_parse_line 09
+seed_lines day_10_instr 10
+
+mkdir -p "$corpus/day_02_rules"
+
+for file in rules/*.txt; do
+    cp "$file" "$corpus/day_02_rules/$(basename "$file")"
+done
+
+mkdir -p "$corpus/solve"
+
+for day in 01 02 03 04 05 06 07 08 09 10; do
+    for file in $(inputs "$day"); do
+        name=$(echo "$file" | tr / -)
+
+        # shellcheck disable=SC2059
+        printf "\\$(printf %03o "${day#0}")" > "$corpus/solve/$name"
+        cat "$file" >> "$corpus/solve/$name"
+    done
+done
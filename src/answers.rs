@@ -0,0 +1,348 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::Display,
+    fs::{read_to_string, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{error::ParseError, solution::Part, utils::parse_as};
+
+#[cfg(test)]
+use crate::input::Inputs;
+
+/// The answers file's name within an inputs directory
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// What the site said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    /// The word(s) between the key and the answer in the answers file
+    fn label(self) -> &'static str {
+        match self {
+            Verdict::Correct => "",
+            Verdict::TooHigh => " too high",
+            Verdict::TooLow => " too low",
+            Verdict::Wrong => " wrong",
+        }
+    }
+}
+
+/// The answers for the puzzle inputs, keyed by day and part: the correct
+/// ones, and any that were submitted and turned out to be wrong
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(usize, Part), String>,
+    wrong: BTreeMap<(usize, Part), Vec<(String, Verdict)>>,
+}
+
+impl KnownAnswers {
+    /// Reads the answers file, treating a missing file as having no answers
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match read_to_string(path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| AnswersError::Parse(path.to_owned(), e))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AnswersError::Io(path.to_owned(), e)),
+        }
+    }
+
+    /// Parses lines of `<day>.<part>: <answer>`, skipping blank lines and `#`
+    /// comments. Wrong answers are written as `<day>.<part> too high: ...`,
+    /// `too low` or `wrong`.
+    pub fn parse(contents: &str) -> Result<Self, ParseError> {
+        let mut known = KnownAnswers::default();
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, verdict, answer) = parse_line(line).map_err(|e| e.on_line(i + 1))?;
+
+            known.insert(key, answer, verdict);
+        }
+
+        Ok(known)
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    fn insert(&mut self, key: (usize, Part), answer: String, verdict: Verdict) {
+        match verdict {
+            Verdict::Correct => {
+                self.answers.insert(key, answer);
+            }
+            _ => self.wrong.entry(key).or_default().push((answer, verdict)),
+        }
+    }
+
+    /// Why `answer` shouldn't be submitted, if it is already known to be
+    /// wrong or the part has already been solved
+    pub fn reason_to_refuse(&self, day: usize, part: Part, answer: &str) -> Option<String> {
+        if let Some(correct) = self.get(day, part) {
+            return Some(format!(
+                "day {} part {} is already solved, the answer is `{}`",
+                day, part, correct
+            ));
+        }
+
+        let wrong = self.wrong.get(&(day, part))?;
+
+        if let Some((_, verdict)) = wrong.iter().find(|(a, _)| a == answer) {
+            return Some(format!(
+                "`{}` was already submitted and was{}",
+                answer,
+                verdict.label()
+            ));
+        }
+
+        let answer_num: i64 = answer.parse().ok()?;
+
+        wrong.iter().find_map(|(a, verdict)| {
+            let a_num: i64 = a.parse().ok()?;
+
+            let out_of_range = match verdict {
+                Verdict::TooHigh => answer_num > a_num,
+                Verdict::TooLow => answer_num < a_num,
+                _ => false,
+            };
+
+            out_of_range.then(|| {
+                format!(
+                    "`{}` can't be right, `{}` was already{}",
+                    answer,
+                    a,
+                    verdict.label()
+                )
+            })
+        })
+    }
+
+    /// Adds an answer to the file at `path`, keeping everything already in it
+    pub fn record(
+        &mut self,
+        path: &Path,
+        day: usize,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+    ) -> io::Result<()> {
+        let existing = match read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let separator = if existing.is_empty() || existing.ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        writeln!(
+            file,
+            "{}{}",
+            separator,
+            format_line(day, part, answer, verdict)
+        )?;
+
+        self.insert((day, part), answer.to_owned(), verdict);
+
+        Ok(())
+    }
+}
+
+/// An answers file that couldn't be loaded
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, ParseError),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(path, e) => write!(f, "couldn't read `{}`: {}", path.display(), e),
+            AnswersError::Parse(path, e) => {
+                write!(f, "invalid answers in `{}`: {}", path.display(), e)
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Io(_, e) => Some(e),
+            AnswersError::Parse(_, e) => Some(e),
+        }
+    }
+}
+
+/// A line of the answers file, as [`KnownAnswers::parse`] reads it
+pub fn format_line(day: usize, part: Part, answer: &str, verdict: Verdict) -> String {
+    format!("{}.{}{}: {}", day, part, verdict.label(), escape(answer))
+}
+
+fn parse_line(line: &str) -> Result<((usize, Part), Verdict, String), ParseError> {
+    let (key, answer) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::new(line, line, "`<day>.<part>: <answer>`"))?;
+
+    let (key, verdict) = match key.split_once(' ') {
+        None => (key, Verdict::Correct),
+        Some((key, "too high")) => (key, Verdict::TooHigh),
+        Some((key, "too low")) => (key, Verdict::TooLow),
+        Some((key, "wrong")) => (key, Verdict::Wrong),
+        Some((_, other)) => {
+            return Err(ParseError::new(
+                line,
+                other,
+                "`too high`, `too low` or `wrong`",
+            ))
+        }
+    };
+
+    let (day, part) = key
+        .split_once('.')
+        .ok_or_else(|| ParseError::new(line, key, "a key like `7.1`"))?;
+
+    let day = parse_as(line, day, "a day number")?;
+    let part = parse_as(line, part, "a part number, 1 or 2")?;
+
+    Ok(((day, part), verdict, unescape(line, answer)?))
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(line: &str, answer: &str) -> Result<String, ParseError> {
+    let mut unescaped = String::with_capacity(answer.len());
+
+    let mut chars = answer.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, 'n')) => unescaped.push('\n'),
+            Some((_, '\\')) => unescaped.push('\\'),
+            Some((j, c)) => {
+                let escape = &answer[i..j + c.len_utf8()];
+
+                return Err(ParseError::new(line, escape, "`\\n` or `\\\\`"));
+            }
+            None => return Err(ParseError::end_of_line(line, "`n` or `\\` after `\\`")),
+        }
+    }
+
+    Ok(unescaped)
+}
+
+/// The known answer for the real input of `day`, for tests to check against
+#[cfg(test)]
+pub fn known_answer(day: usize, part: Part) -> String {
+    let path = Inputs::default().answers_path().unwrap();
+
+    KnownAnswers::load(&path)
+        .unwrap()
+        .get(day, part)
+        .unwrap_or_else(|| {
+            panic!(
+                "No known answer for day {} part {}, add it to `{}`",
+                day,
+                part,
+                path.display()
+            )
+        })
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DAYS;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = KnownAnswers::parse("# Comment\n\n7.1: 1477771\n10.2: \\n#.\\\\\\n").unwrap();
+
+        assert_eq!(answers.get(7, Part::One), Some("1477771"));
+        assert_eq!(answers.get(7, Part::Two), None);
+        assert_eq!(answers.get(10, Part::Two), Some("\n#.\\\n"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = KnownAnswers::parse("7.1: 1477771\n7.3: 3579501").unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "3"));
+
+        let e = KnownAnswers::parse("10.2: \\t").unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 7, "\\t"));
+    }
+
+    #[test]
+    fn test_escape_round_trips() {
+        let answer = "\n#.\\\n";
+
+        assert_eq!(unescape("", &escape(answer)).unwrap(), answer);
+    }
+
+    #[test]
+    fn test_reason_to_refuse() {
+        let known =
+            KnownAnswers::parse("7.1 too high: 5000\n7.1 too low: 100\n7.1 wrong: 1234\n8.1: 1676")
+                .unwrap();
+
+        let refuse = |day, answer| known.reason_to_refuse(day, Part::One, answer);
+
+        assert_eq!(refuse(7, "2000"), None);
+        assert_eq!(refuse(7, "GFTNRBZPF"), None);
+        assert_eq!(
+            refuse(7, "1234").unwrap(),
+            "`1234` was already submitted and was wrong"
+        );
+        assert_eq!(
+            refuse(7, "6000").unwrap(),
+            "`6000` can't be right, `5000` was already too high"
+        );
+        assert_eq!(
+            refuse(7, "99").unwrap(),
+            "`99` can't be right, `100` was already too low"
+        );
+        assert_eq!(
+            refuse(8, "1676").unwrap(),
+            "day 8 part 1 is already solved, the answer is `1676`"
+        );
+    }
+
+    #[test]
+    fn test_answers_file() {
+        for day in 1..=DAYS.len() {
+            for part in Part::BOTH {
+                assert!(!known_answer(day, part).is_empty());
+            }
+        }
+    }
+}
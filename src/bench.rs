@@ -0,0 +1,274 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    day::{Day, Solver},
+    day_01::{self, Day01},
+    error::{ParseError, ReadError},
+    solution::Timings,
+};
+
+/// Summary of a set of timing samples
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        Stats {
+            min: percentile(&samples, 0),
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+        }
+    }
+}
+
+impl std::ops::Add for Stats {
+    type Output = Stats;
+
+    fn add(self, other: Stats) -> Stats {
+        Stats {
+            min: self.min + other.min,
+            median: self.median + other.median,
+            p95: self.p95 + other.p95,
+        }
+    }
+}
+
+/// Nearest-rank percentile of already sorted samples
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let rank = (p * sorted.len()).div_ceil(100);
+
+    sorted[rank.saturating_sub(1)]
+}
+
+pub struct DayStats {
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+    pub total: Stats,
+}
+
+/// Times `iterations` runs of a day after `warmup` untimed runs
+pub fn bench_day(
+    solver: &dyn Solver,
+    input: &str,
+    iterations: usize,
+    warmup: usize,
+) -> Result<DayStats, ParseError> {
+    for _ in 0..warmup {
+        solver.solve(input, None)?;
+    }
+
+    let samples = (0..iterations)
+        .map(|_| solver.solve(input, None).map(|(_, timings)| timings))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let stats_of =
+        |phase: fn(&Timings) -> Duration| Stats::from_samples(samples.iter().map(phase).collect());
+
+    Ok(DayStats {
+        parse: stats_of(|t| t.parse),
+        part_one: stats_of(|t| t.part_one.unwrap_or_default()),
+        part_two: stats_of(|t| t.part_two.unwrap_or_default()),
+        total: stats_of(Timings::total),
+    })
+}
+
+/// Timings of each way of finding day 1's biggest calorie counts
+pub struct TopNStats {
+    pub single: Stats,
+    pub streamed: Stats,
+    pub parallel: Stats,
+    /// Whether every way found the same counts
+    pub agree: bool,
+}
+
+/// Times finding the `n` biggest calorie counts in `input` on one thread, a
+/// line at a time, and split across `jobs` threads
+pub fn bench_top_n(
+    input: &str,
+    n: usize,
+    jobs: usize,
+    iterations: usize,
+    warmup: usize,
+) -> Result<TopNStats, ReadError> {
+    let (single, expected) = time(iterations, warmup, || {
+        Day01::parse(input).map(|calories| day_01::top_n(calories, n))
+    });
+
+    let (streamed, streamed_top) = time(iterations, warmup, || {
+        day_01::top_n_from_reader(input.as_bytes(), n)
+    });
+
+    let (parallel, parallel_top) = time(iterations, warmup, || {
+        day_01::top_n_parallel(input, n, jobs)
+    });
+
+    let expected = expected?;
+
+    Ok(TopNStats {
+        single,
+        streamed,
+        parallel,
+        agree: streamed_top? == expected && parallel_top? == expected,
+    })
+}
+
+/// Times `iterations` calls to `f` after `warmup` untimed ones, also giving
+/// the result of the last call
+fn time<T>(iterations: usize, warmup: usize, mut f: impl FnMut() -> T) -> (Stats, T) {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut last = None;
+
+    for _ in 0..iterations {
+        let started = Instant::now();
+        let result = black_box(f());
+
+        samples.push(started.elapsed());
+        last = Some(result);
+    }
+
+    (Stats::from_samples(samples), last.unwrap_or_else(f))
+}
+
+pub fn header() -> String {
+    format!(
+        "{:>5}  {:<8}  {:>10}  {:>10}  {:>10}\n",
+        "Day", "Phase", "Min", "Median", "P95"
+    )
+}
+
+/// The rows for each of `day`'s phases and its total
+pub fn day_rows(day: usize, stats: &DayStats) -> String {
+    let rows = [
+        ("parse", stats.parse),
+        ("part one", stats.part_one),
+        ("part two", stats.part_two),
+        ("total", stats.total),
+    ];
+
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, (phase, stats))| {
+            let day = if i == 0 {
+                day.to_string()
+            } else {
+                String::new()
+            };
+
+            row(&day, phase, stats)
+        })
+        .collect()
+}
+
+/// The rows for each way of finding day 1's top calorie counts, and how much
+/// faster splitting it across `jobs` threads was
+pub fn top_n_rows(jobs: usize, stats: &TopNStats) -> String {
+    let speed_up = stats.single.median.as_secs_f64() / stats.parallel.median.as_secs_f64();
+
+    format!(
+        "{}{}{}\nWith --jobs {} the median is {:.2}x as fast as on one thread\n",
+        row("1", "single", stats.single),
+        row("", "streamed", stats.streamed),
+        row("", "parallel", stats.parallel),
+        jobs,
+        speed_up
+    )
+}
+
+pub fn grand_total_row(total: Stats) -> String {
+    row("All", "total", total)
+}
+
+fn row(day: &str, phase: &str, stats: Stats) -> String {
+    format!(
+        "{:>5}  {:<8}  {:>10}  {:>10}  {:>10}\n",
+        day,
+        phase,
+        FormatDuration(stats.min),
+        FormatDuration(stats.median),
+        FormatDuration(stats.p95)
+    )
+}
+
+/// Formats a duration with a unit that keeps the number readable
+pub struct FormatDuration(pub Duration);
+
+impl Display for FormatDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+
+        let formatted = if nanos < 1_000.0 {
+            format!("{}ns", nanos)
+        } else if nanos < 1_000_000.0 {
+            format!("{:.2}µs", nanos / 1_000.0)
+        } else if nanos < 1_000_000_000.0 {
+            format!("{:.2}ms", nanos / 1_000_000.0)
+        } else {
+            format!("{:.2}s", nanos / 1_000_000_000.0)
+        };
+
+        f.pad(&formatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = micros(&[
+            20, 1, 19, 2, 18, 3, 17, 4, 16, 5, 15, 6, 14, 7, 13, 8, 12, 9, 11, 10,
+        ]);
+
+        assert_eq!(
+            Stats::from_samples(samples),
+            Stats {
+                min: Duration::from_micros(1),
+                median: Duration::from_micros(10),
+                p95: Duration::from_micros(19),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(micros(&[7]));
+
+        assert_eq!(stats.min, Duration::from_micros(7));
+        assert_eq!(stats.median, Duration::from_micros(7));
+        assert_eq!(stats.p95, Duration::from_micros(7));
+    }
+
+    #[test_case(Duration::from_nanos(950), "950ns")]
+    #[test_case(Duration::from_nanos(12_346), "12.35µs")]
+    #[test_case(Duration::from_micros(4_500), "4.50ms")]
+    #[test_case(Duration::from_millis(2_250), "2.25s")]
+    fn test_format_duration(duration: Duration, expected: &str) {
+        assert_eq!(FormatDuration(duration).to_string(), expected);
+    }
+}
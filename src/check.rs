@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use crate::{answers::KnownAnswers, solution::Part};
+
+/// How an answer compares to the one in the registry
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Outcome {
+    pub fn of(known: &KnownAnswers, day: usize, part: Part, actual: &str) -> Self {
+        match known.get(day, part) {
+            None => Outcome::Unknown,
+            Some(expected) if expected == actual => Outcome::Pass,
+            Some(expected) => Outcome::Fail {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+/// Running counts of each outcome
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Fail { .. } => self.failed += 1,
+            Outcome::Unknown => self.unknown += 1,
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unknown",
+            self.passed, self.failed, self.unknown
+        )
+    }
+}
+
+/// The lines reporting `outcome`, with a diff against the expected answer if
+/// it failed
+pub fn format_outcome(day: usize, part: Part, actual: &str, outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Pass => format!("Day {:>2} part {}: pass\n", day, part),
+        Outcome::Unknown => format!("Day {:>2} part {}: unknown ({})\n", day, part, actual),
+        Outcome::Fail { expected } => {
+            let mut lines = format!("Day {:>2} part {}: FAIL\n", day, part);
+
+            for line in diff(expected, actual) {
+                lines += &format!("    {}\n", line);
+            }
+
+            lines
+        }
+    }
+}
+
+/// Compares `expected` and `actual` line by line, marking lines only in the
+/// expected answer with `-` and lines only in the actual answer with `+`
+fn diff(expected: &str, actual: &str) -> Vec<String> {
+    let expected: Vec<_> = expected.split('\n').collect();
+    let actual: Vec<_> = actual.split('\n').collect();
+
+    let mut lines = Vec::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => lines.push(format!("  {}", e)),
+            (e, a) => {
+                lines.extend(e.map(|e| format!("- {}", e)));
+                lines.extend(a.map(|a| format!("+ {}", a)));
+            }
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case("7.1: 1477771", "1477771", Outcome::Pass)]
+    #[test_case("7.1: 1477771", "1477772", Outcome::Fail { expected: "1477771".into() })]
+    #[test_case("7.2: 3579501", "1477771", Outcome::Unknown)]
+    fn test_outcome(answers: &str, actual: &str, expected: Outcome) {
+        let known = KnownAnswers::parse(answers).unwrap();
+
+        assert_eq!(Outcome::of(&known, 7, Part::One, actual), expected);
+    }
+
+    #[test]
+    fn test_format_outcome() {
+        let outcome = Outcome::Fail {
+            expected: "1477771".into(),
+        };
+
+        assert_eq!(
+            format_outcome(7, Part::One, "1477772", &outcome),
+            "Day  7 part 1: FAIL\n    - 1477771\n    + 1477772\n"
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff("\n##..\n#..#", "\n##..\n#.##\n"),
+            ["  ", "  ##..", "- #..#", "+ #.##", "+ "]
+        );
+    }
+}
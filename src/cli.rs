@@ -0,0 +1,778 @@
+use std::{env, fmt::Display, num::NonZeroUsize, path::PathBuf, str::FromStr, time::Duration};
+
+use crate::{
+    client::{BASE_URL_VAR, DEFAULT_BASE_URL},
+    fetch::LAST_DAY,
+    generate::GENERATORS,
+    input::{Inputs, DEFAULT_INPUTS_DIR, INPUTS_VAR},
+    solution::{Format, Part},
+};
+
+pub const USAGE: &str = "\
+Usage: advent-of-code-2022 [run] [DAYS...] [--part 1|2] [--format text|json|csv] [--jobs N] [--example NAME] [--top N] [--rules FILE] [INPUTS]
+       advent-of-code-2022 bench [DAYS...] [--iterations N] [--warmup N] [--top N [--jobs N]] [INPUTS]
+       advent-of-code-2022 check [DAYS...] [INPUTS]
+       advent-of-code-2022 fetch [DAYS...] [--base-url URL] [--delay SECS] [INPUTS]
+       advent-of-code-2022 submit DAY PART [--answer ANSWER] [--base-url URL] [--delay SECS] [INPUTS]
+       advent-of-code-2022 new DAY [INPUTS]
+       advent-of-code-2022 watch DAY [INPUTS]
+       advent-of-code-2022 gen DAY [--size N] [--seed S]
+       advent-of-code-2022 inventory [--format text|json] [--example NAME] [INPUTS]
+
+DAYS can be a single day (`7`), an inclusive range (`3..=6`), a half-open
+range (`3..6`) or `latest`. With no days given every day is run.
+
+`--jobs N` solves up to N days at once, still printing them in order, and
+reports the wall-clock time against the time taken by each day added up.
+With `--top` it splits day 1's input across N threads instead.
+
+`--example NAME` solves the puzzle example `examples/day_NN/NAME.txt`
+instead of the input, warning about any answer that differs from the one
+expected in `examples/day_NN/NAME.answers`.
+
+`--top N` lists the N elves carrying the most calories on day 1, biggest
+first, along with their total, instead of the answers. It reads the input a
+line at a time, so it can be bigger than memory, and decompresses it first if
+it's gzipped.
+
+`--rules FILE` plays day 2 by the rules of another hand game like rock,
+paper, scissors, with any odd number of shapes. The file gives the shapes in
+order, each beating the half of the others before it, along with their
+scores, letters and the scores for each outcome. See `rules/` for the
+puzzle's own game and some others.
+
+INPUTS are `--inputs DIR` (or the AOC_INPUTS environment variable) to read
+`DIR/day_NN.txt` instead of `inputs/day_NN.txt`, `--set NAME` to read from
+`DIR/NAME/` and `--inputs -` to read a single day's input from stdin.
+
+`bench 1 --top N` times finding day 1's N biggest calorie counts on one
+thread, a line at a time and split across `--jobs` threads (default: one per
+CPU), failing if they don't all find the same counts.
+
+`fetch` downloads any inputs that aren't already saved, using the session
+token in AOC_SESSION or the inputs directory's `.session` file. It waits at
+least `--delay` seconds (default 3) between requests.
+
+`submit` solves a day's part and posts the answer (or `--answer` instead),
+recording the result in the inputs directory's `answers.txt`. Answers that
+are already known to be wrong aren't sent.
+
+`new` creates `src/day_NN.rs` for the next day from a template, registers it
+in `src/lib.rs` and creates an empty input file and example for it.
+
+`watch` solves a day again whenever its input or `src/day_NN.rs` changes,
+rebuilding first if the source changed, and shows how the answers changed.
+
+`gen` prints a random input for a day that its parser accepts, with
+`--size` (default 1000) elves, rounds, moves and so on depending on the day.
+The same `--seed` (default 0) always gives the same input.
+
+`inventory` reports on what each elf carries on day 1: every elf's number of
+items, total and largest and smallest item, then the mean, median and
+standard deviation of the totals and a histogram of them.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        days: Vec<usize>,
+        part: Option<Part>,
+        format: Format,
+        jobs: Option<NonZeroUsize>,
+        /// Solve the example with this name instead of the input
+        example: Option<String>,
+        /// List this many of day 1's biggest calorie counts instead
+        top: Option<NonZeroUsize>,
+        /// Play day 2 by the rules in this file
+        rules: Option<PathBuf>,
+        inputs: Inputs,
+    },
+    Bench {
+        days: Vec<usize>,
+        iterations: NonZeroUsize,
+        warmup: usize,
+        /// Time finding this many of day 1's biggest calorie counts instead
+        top: Option<NonZeroUsize>,
+        /// How many threads to split day 1 across for `top`
+        jobs: Option<NonZeroUsize>,
+        inputs: Inputs,
+    },
+    Check {
+        days: Vec<usize>,
+        inputs: Inputs,
+    },
+    Fetch {
+        days: Vec<usize>,
+        inputs: Inputs,
+        base_url: String,
+        delay: Duration,
+    },
+    Submit {
+        day: usize,
+        part: Part,
+        answer: Option<String>,
+        inputs: Inputs,
+        base_url: String,
+        delay: Duration,
+    },
+    New {
+        day: usize,
+        inputs: Inputs,
+    },
+    Watch {
+        day: usize,
+        inputs: Inputs,
+    },
+    Gen {
+        day: usize,
+        size: usize,
+        seed: u64,
+    },
+    Inventory {
+        format: Format,
+        example: Option<String>,
+        inputs: Inputs,
+    },
+}
+
+impl Command {
+    /// Parses the command line arguments (without the program name), checking
+    /// every selected day against the `available` days `1..=available`.
+    pub fn parse<I>(args: I, available: usize) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = RawArgs::new(args)?;
+
+        let command = match args.subcommand(&[
+            "run",
+            "bench",
+            "check",
+            "fetch",
+            "submit",
+            "new",
+            "watch",
+            "gen",
+            "inventory",
+        ]) {
+            Some("bench") => {
+                let top = args.flag("--top", &[])?;
+
+                Command::Bench {
+                    iterations: args
+                        .flag("--iterations", &["-n"])?
+                        .unwrap_or(NonZeroUsize::new(100).unwrap()),
+                    warmup: args.flag("--warmup", &[])?.unwrap_or(10),
+                    // Left for `finish` to reject without `--top`
+                    jobs: match top {
+                        Some(_) => args.flag("--jobs", &["-j"])?,
+                        None => None,
+                    },
+                    top,
+                    inputs: args.inputs()?,
+                    days: args.days(available)?,
+                }
+            }
+            Some("check") => Command::Check {
+                inputs: args.inputs()?,
+                days: args.days(available)?,
+            },
+            Some("fetch") => Command::Fetch {
+                inputs: args.inputs()?,
+                base_url: args.base_url()?,
+                delay: args.delay()?,
+                days: args.days(LAST_DAY)?,
+            },
+            Some("submit") => Command::Submit {
+                day: args.day(available)?,
+                part: args
+                    .positional("PART")
+                    .and_then(|p| p.parse().map_err(|_| ArgsError::InvalidPart(p)))?,
+                answer: args.flag("--answer", &[])?,
+                inputs: args.inputs()?,
+                base_url: args.base_url()?,
+                delay: args.delay()?,
+            },
+            Some("new") => Command::New {
+                day: args.day(LAST_DAY)?,
+                inputs: args.inputs()?,
+            },
+            Some("watch") => Command::Watch {
+                day: args.day(available)?,
+                inputs: args.inputs()?,
+            },
+            Some("gen") => Command::Gen {
+                day: match args.day(available)? {
+                    day if day > GENERATORS.len() => return Err(ArgsError::NoGenerator(day)),
+                    day => day,
+                },
+                size: args.flag("--size", &["-s"])?.unwrap_or(1000),
+                seed: args.flag("--seed", &[])?.unwrap_or(0),
+            },
+            Some("inventory") => Command::Inventory {
+                format: args.flag("--format", &["-f"])?.unwrap_or_default(),
+                example: args.flag("--example", &["-e"])?,
+                inputs: args.inputs()?,
+            },
+            _ => Command::Run {
+                part: args.part()?,
+                format: args.flag("--format", &["-f"])?.unwrap_or_default(),
+                jobs: args.flag("--jobs", &["-j"])?,
+                example: args.flag("--example", &["-e"])?,
+                top: args.flag("--top", &[])?,
+                rules: args.flag("--rules", &[])?,
+                inputs: args.inputs()?,
+                days: args.days(available)?,
+            },
+        };
+
+        args.finish()?;
+
+        match &command {
+            Command::Run {
+                example: Some(_),
+                inputs: Inputs::Stdin,
+                ..
+            } => return Err(ArgsError::NotWithStdin("--example")),
+            Command::Run {
+                top: Some(_),
+                part: Some(_),
+                ..
+            } => return Err(ArgsError::Conflict("--top", "--part")),
+            Command::Run {
+                top: Some(_), days, ..
+            }
+            | Command::Bench {
+                top: Some(_), days, ..
+            } if days != &[1] => return Err(ArgsError::OnlyForDay("--top", 1)),
+            Command::Run {
+                rules: Some(_),
+                days,
+                ..
+            } if days != &[2] => return Err(ArgsError::OnlyForDay("--rules", 2)),
+            Command::Run { days, inputs, .. }
+            | Command::Bench { days, inputs, .. }
+            | Command::Check { days, inputs } => {
+                if *inputs == Inputs::Stdin && days.len() != 1 {
+                    return Err(ArgsError::StdinNeedsOneDay);
+                }
+            }
+            Command::Fetch { inputs, .. } => {
+                if *inputs == Inputs::Stdin {
+                    return Err(ArgsError::NotWithStdin("fetch"));
+                }
+            }
+            Command::Submit { inputs, .. } => {
+                if *inputs == Inputs::Stdin {
+                    return Err(ArgsError::NotWithStdin("submit"));
+                }
+            }
+            Command::New { inputs, .. } => {
+                if *inputs == Inputs::Stdin {
+                    return Err(ArgsError::NotWithStdin("new"));
+                }
+            }
+            Command::Watch { inputs, .. } => {
+                if *inputs == Inputs::Stdin {
+                    return Err(ArgsError::NotWithStdin("watch"));
+                }
+            }
+            Command::Gen { .. } => {}
+            Command::Inventory {
+                format: Format::Csv,
+                ..
+            } => {
+                return Err(ArgsError::InvalidValue {
+                    flag: "--format",
+                    value: "csv".to_owned(),
+                })
+            }
+            Command::Inventory {
+                example: Some(_),
+                inputs: Inputs::Stdin,
+                ..
+            } => return Err(ArgsError::NotWithStdin("--example")),
+            Command::Inventory { .. } => {}
+        }
+
+        Ok(command)
+    }
+}
+
+/// Command line arguments split into positionals and `--flag value` pairs,
+/// which each subcommand then takes the parts it understands from.
+struct RawArgs {
+    positionals: Vec<String>,
+    flags: Vec<(String, String)>,
+}
+
+impl RawArgs {
+    fn new<I>(args: I) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+
+        let mut positionals = Vec::new();
+        let mut flags = Vec::new();
+
+        while let Some(arg) = args.next() {
+            if !arg.starts_with('-') || arg == "-" {
+                positionals.push(arg);
+            } else if let Some((flag, value)) = arg.split_once('=') {
+                flags.push((flag.to_owned(), value.to_owned()));
+            } else {
+                let value = args.next().ok_or(ArgsError::MissingValue(arg.clone()))?;
+
+                flags.push((arg, value));
+            }
+        }
+
+        Ok(RawArgs { positionals, flags })
+    }
+
+    /// Takes the first positional argument if it is one of the `known`
+    /// subcommands.
+    fn subcommand(&mut self, known: &[&'static str]) -> Option<&'static str> {
+        let first = self.positionals.first()?;
+
+        let name = known.iter().copied().find(|&name| name == first)?;
+
+        self.positionals.remove(0);
+
+        Some(name)
+    }
+
+    /// Takes the last value given for `name` (or one of its `aliases`)
+    fn flag<T: FromStr>(
+        &mut self,
+        name: &'static str,
+        aliases: &[&str],
+    ) -> Result<Option<T>, ArgsError> {
+        let mut value = None;
+
+        self.flags.retain(|(flag, v)| {
+            let matches = flag == name || aliases.contains(&flag.as_str());
+
+            if matches {
+                value = Some(v.clone());
+            }
+
+            !matches
+        });
+
+        value
+            .map(|v| {
+                v.parse().map_err(|_| ArgsError::InvalidValue {
+                    flag: name,
+                    value: v,
+                })
+            })
+            .transpose()
+    }
+
+    fn part(&mut self) -> Result<Option<Part>, ArgsError> {
+        self.flag::<String>("--part", &["-p"])?
+            .map(|p| p.parse().map_err(|_| ArgsError::InvalidPart(p)))
+            .transpose()
+    }
+
+    /// Takes `--inputs` (falling back to the environment) and `--set`
+    fn inputs(&mut self) -> Result<Inputs, ArgsError> {
+        let dir = match self.flag::<String>("--inputs", &["-i"])? {
+            Some(dir) => dir,
+            None => env::var(INPUTS_VAR).unwrap_or_else(|_| DEFAULT_INPUTS_DIR.to_owned()),
+        };
+
+        let set = self.flag::<String>("--set", &[])?;
+
+        if dir == "-" && set.is_some() {
+            return Err(ArgsError::NotWithStdin("--set"));
+        }
+
+        Ok(Inputs::new(&dir, set.as_deref()))
+    }
+
+    /// Takes the next positional argument, which must be there
+    fn positional(&mut self, name: &'static str) -> Result<String, ArgsError> {
+        if self.positionals.is_empty() {
+            return Err(ArgsError::MissingArgument(name));
+        }
+
+        Ok(self.positionals.remove(0))
+    }
+
+    /// Takes a single day, which must be one of `1..=available`
+    fn day(&mut self, available: usize) -> Result<usize, ArgsError> {
+        let arg = self.positional("DAY")?;
+
+        let day = arg.parse().map_err(|_| ArgsError::InvalidDays(arg))?;
+
+        if day == 0 || day > available {
+            return Err(ArgsError::UnknownDay { day, available });
+        }
+
+        Ok(day)
+    }
+
+    fn base_url(&mut self) -> Result<String, ArgsError> {
+        Ok(self
+            .flag("--base-url", &[])?
+            .or_else(|| env::var(BASE_URL_VAR).ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned()))
+    }
+
+    fn delay(&mut self) -> Result<Duration, ArgsError> {
+        let Some(secs) = self.flag::<f64>("--delay", &[])? else {
+            return Ok(Duration::from_secs(3));
+        };
+
+        Duration::try_from_secs_f64(secs).map_err(|_| ArgsError::InvalidValue {
+            flag: "--delay",
+            value: secs.to_string(),
+        })
+    }
+
+    /// Takes every remaining positional argument as a day selection
+    fn days(&mut self, available: usize) -> Result<Vec<usize>, ArgsError> {
+        let mut days = Vec::new();
+
+        for arg in self.positionals.drain(..) {
+            if arg == "latest" {
+                days.push(available);
+            } else {
+                days.extend(parse_days(&arg)?);
+            }
+        }
+
+        if days.is_empty() {
+            days.extend(1..=available);
+        }
+
+        if let Some(&day) = days.iter().find(|&&d| d == 0 || d > available) {
+            return Err(ArgsError::UnknownDay { day, available });
+        }
+
+        days.sort_unstable();
+        days.dedup();
+
+        Ok(days)
+    }
+
+    /// Errors on anything the subcommand didn't take
+    fn finish(self) -> Result<(), ArgsError> {
+        if let Some((flag, _)) = self.flags.into_iter().next() {
+            return Err(ArgsError::UnknownFlag(flag));
+        }
+
+        if let Some(arg) = self.positionals.into_iter().next() {
+            return Err(ArgsError::UnexpectedArgument(arg));
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_days(s: &str) -> Result<impl Iterator<Item = usize>, ArgsError> {
+    let parse = |d: &str| {
+        d.parse::<usize>()
+            .map_err(|_| ArgsError::InvalidDays(s.to_owned()))
+    };
+
+    let range = if let Some((start, end)) = s.split_once("..=") {
+        parse(start)?..=parse(end)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let end = parse(end)?;
+
+        if end == 0 {
+            return Err(ArgsError::InvalidDays(s.to_owned()));
+        }
+
+        parse(start)?..=end - 1
+    } else {
+        let day = parse(s)?;
+
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(ArgsError::InvalidDays(s.to_owned()));
+    }
+
+    Ok(range)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    MissingValue(String),
+    MissingArgument(&'static str),
+    UnknownFlag(String),
+    UnexpectedArgument(String),
+    InvalidValue { flag: &'static str, value: String },
+    InvalidPart(String),
+    InvalidDays(String),
+    UnknownDay { day: usize, available: usize },
+    StdinNeedsOneDay,
+    NotWithStdin(&'static str),
+    Conflict(&'static str, &'static str),
+    OnlyForDay(&'static str, usize),
+    NoGenerator(usize),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgsError::MissingValue(flag) => write!(f, "{} expects a value", flag),
+            ArgsError::MissingArgument(name) => write!(f, "missing {}", name),
+            ArgsError::UnknownFlag(flag) => write!(f, "unknown flag `{}`", flag),
+            ArgsError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{}`", arg),
+            ArgsError::InvalidValue { flag, value } => {
+                write!(f, "invalid value `{}` for {}", value, flag)
+            }
+            ArgsError::InvalidPart(part) => {
+                write!(f, "invalid part `{}`, expected `1` or `2`", part)
+            }
+            ArgsError::InvalidDays(days) => write!(
+                f,
+                "invalid day selection `{}`, expected a day like `7` or a range like `3..=6`",
+                days
+            ),
+            ArgsError::UnknownDay { day, available } => write!(
+                f,
+                "there is no solution for day {}, only days 1 to {} are available",
+                day, available
+            ),
+            ArgsError::StdinNeedsOneDay => {
+                write!(f, "reading from stdin needs exactly one day to be selected")
+            }
+            ArgsError::NotWithStdin(what) => {
+                write!(f, "{} can't be used when reading from stdin", what)
+            }
+            ArgsError::Conflict(flag, other) => {
+                write!(f, "{} can't be used together with {}", flag, other)
+            }
+            ArgsError::OnlyForDay(flag, day) => {
+                write!(f, "{} can only be used with day {} on its own", flag, day)
+            }
+            ArgsError::NoGenerator(day) => write!(
+                f,
+                "there is no input generator for day {} yet, add one to src/generate.rs",
+                day
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    fn parse(args: &str) -> Result<Command, ArgsError> {
+        Command::parse(args.split_whitespace().map(String::from), 10)
+    }
+
+    fn run_args(args: &str) -> (Vec<usize>, Option<Part>, Format) {
+        match parse(args).unwrap() {
+            Command::Run {
+                days, part, format, ..
+            } => (days, part, format),
+            other => panic!("Expected a run command, got {:?}", other),
+        }
+    }
+
+    #[test_case("", &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10])]
+    #[test_case("7", &[7])]
+    #[test_case("run 7", &[7])]
+    #[test_case("3..=6", &[3, 4, 5, 6])]
+    #[test_case("3..6", &[3, 4, 5])]
+    #[test_case("latest", &[10])]
+    #[test_case("9 2 2..=3", &[2, 3, 9])]
+    fn test_day_selection(args: &str, expected: &[usize]) {
+        assert_eq!(run_args(args).0, expected);
+    }
+
+    #[test_case("--part 2", Some(Part::Two))]
+    #[test_case("7 -p 1", Some(Part::One))]
+    #[test_case("--part=1 latest", Some(Part::One))]
+    #[test_case("3..=6", None)]
+    fn test_part_selection(args: &str, expected: Option<Part>) {
+        assert_eq!(run_args(args).1, expected);
+    }
+
+    #[test_case("", Format::Text)]
+    #[test_case("--format json", Format::Json)]
+    #[test_case("3 -f csv", Format::Csv)]
+    fn test_format(args: &str, expected: Format) {
+        assert_eq!(run_args(args).2, expected);
+    }
+
+    #[test_case("bench", Command::Bench { days: (1..=10).collect(), iterations: NonZeroUsize::new(100).unwrap(), warmup: 10, top: None, jobs: None, inputs: Inputs::default() })]
+    #[test_case("bench 6 -n 1000 --warmup=0", Command::Bench { days: vec![6], iterations: NonZeroUsize::new(1000).unwrap(), warmup: 0, top: None, jobs: None, inputs: Inputs::default() })]
+    #[test_case("bench 1 --top 10 -j 4", Command::Bench { days: vec![1], iterations: NonZeroUsize::new(100).unwrap(), warmup: 10, top: NonZeroUsize::new(10), jobs: NonZeroUsize::new(4), inputs: Inputs::default() })]
+    fn test_bench(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test_case("check", Command::Check { days: (1..=10).collect(), inputs: Inputs::default() })]
+    #[test_case("check 2..=4", Command::Check { days: vec![2, 3, 4], inputs: Inputs::default() })]
+    fn test_check(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test_case("", None)]
+    #[test_case("--jobs 4", NonZeroUsize::new(4))]
+    #[test_case("3..=6 -j 2", NonZeroUsize::new(2))]
+    fn test_jobs(args: &str, expected: Option<NonZeroUsize>) {
+        match parse(args).unwrap() {
+            Command::Run { jobs, .. } => assert_eq!(jobs, expected),
+            other => panic!("Expected a run command, got {:?}", other),
+        }
+    }
+
+    #[test_case("", None)]
+    #[test_case("9 --example two", Some("two"))]
+    #[test_case("-e one", Some("one"))]
+    fn test_example(args: &str, expected: Option<&str>) {
+        match parse(args).unwrap() {
+            Command::Run { example, .. } => assert_eq!(example.as_deref(), expected),
+            other => panic!("Expected a run command, got {:?}", other),
+        }
+    }
+
+    #[test_case("1", None)]
+    #[test_case("1 --top 10", NonZeroUsize::new(10))]
+    #[test_case("--top=25 1 -i -", NonZeroUsize::new(25))]
+    fn test_top(args: &str, expected: Option<NonZeroUsize>) {
+        match parse(args).unwrap() {
+            Command::Run { top, .. } => assert_eq!(top, expected),
+            other => panic!("Expected a run command, got {:?}", other),
+        }
+    }
+
+    #[test_case("2", None)]
+    #[test_case(
+        "2 --rules rules/rock_paper_scissors_7.txt",
+        Some("rules/rock_paper_scissors_7.txt")
+    )]
+    #[test_case("--rules=custom.txt 2 -p 1", Some("custom.txt"))]
+    fn test_rules(args: &str, expected: Option<&str>) {
+        match parse(args).unwrap() {
+            Command::Run { rules, .. } => assert_eq!(rules, expected.map(PathBuf::from)),
+            other => panic!("Expected a run command, got {:?}", other),
+        }
+    }
+
+    #[test_case("7 --inputs /tmp/aoc", Inputs::Dir("/tmp/aoc".into()))]
+    #[test_case("--set alice", Inputs::Dir("inputs/alice".into()))]
+    #[test_case("-i=/tmp/aoc --set bob", Inputs::Dir("/tmp/aoc/bob".into()))]
+    #[test_case("7 -i -", Inputs::Stdin)]
+    fn test_inputs(args: &str, expected: Inputs) {
+        match parse(args).unwrap() {
+            Command::Run { inputs, .. } => assert_eq!(inputs, expected),
+            other => panic!("Expected a run command, got {:?}", other),
+        }
+    }
+
+    #[test_case("fetch 11", Command::Fetch { days: vec![11], inputs: Inputs::default(), base_url: DEFAULT_BASE_URL.into(), delay: Duration::from_secs(3) })]
+    #[test_case("fetch 1..=2 --base-url http://localhost:8000 --delay 0.5 --set alice", Command::Fetch { days: vec![1, 2], inputs: Inputs::Dir("inputs/alice".into()), base_url: "http://localhost:8000".into(), delay: Duration::from_millis(500) })]
+    fn test_fetch(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test_case("submit 7 2", Command::Submit { day: 7, part: Part::Two, answer: None, inputs: Inputs::default(), base_url: DEFAULT_BASE_URL.into(), delay: Duration::from_secs(3) })]
+    #[test_case("submit 10 2 --answer EHZFZHCZ --set alice", Command::Submit { day: 10, part: Part::Two, answer: Some("EHZFZHCZ".into()), inputs: Inputs::Dir("inputs/alice".into()), base_url: DEFAULT_BASE_URL.into(), delay: Duration::from_secs(3) })]
+    fn test_submit(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test_case("new 11", Command::New { day: 11, inputs: Inputs::default() })]
+    #[test_case("new 12 --set alice", Command::New { day: 12, inputs: Inputs::Dir("inputs/alice".into()) })]
+    fn test_new(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test_case("watch 7", Command::Watch { day: 7, inputs: Inputs::default() })]
+    #[test_case("watch 3 --set alice", Command::Watch { day: 3, inputs: Inputs::Dir("inputs/alice".into()) })]
+    fn test_watch(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test_case("gen 5", Command::Gen { day: 5, size: 1000, seed: 0 })]
+    #[test_case("gen 8 --size 50 --seed 7", Command::Gen { day: 8, size: 50, seed: 7 })]
+    #[test_case("gen 1 -s=10", Command::Gen { day: 1, size: 10, seed: 0 })]
+    fn test_gen(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_gen_for_a_day_without_a_generator() {
+        // As after `new 11`, with a solution but no generator
+        let args = ["gen", "11"].map(String::from);
+
+        assert_eq!(Command::parse(args, 11), Err(ArgsError::NoGenerator(11)));
+    }
+
+    #[test_case("inventory", Command::Inventory { format: Format::Text, example: None, inputs: Inputs::default() })]
+    #[test_case("inventory -f json -e one", Command::Inventory { format: Format::Json, example: Some("one".into()), inputs: Inputs::default() })]
+    #[test_case("inventory --inputs -", Command::Inventory { format: Format::Text, example: None, inputs: Inputs::Stdin })]
+    fn test_inventory(args: &str, expected: Command) {
+        assert_eq!(parse(args).unwrap(), expected);
+    }
+
+    #[test_case("11", ArgsError::UnknownDay { day: 11, available: 10 })]
+    #[test_case("0", ArgsError::UnknownDay { day: 0, available: 10 })]
+    #[test_case("8..=12", ArgsError::UnknownDay { day: 11, available: 10 })]
+    #[test_case("6..=3", ArgsError::InvalidDays("6..=3".into()))]
+    #[test_case("seven", ArgsError::InvalidDays("seven".into()))]
+    #[test_case("--part 3", ArgsError::InvalidPart("3".into()))]
+    #[test_case("--part", ArgsError::MissingValue("--part".into()))]
+    #[test_case("--verbose 1", ArgsError::UnknownFlag("--verbose".into()))]
+    #[test_case("bench --part 1", ArgsError::UnknownFlag("--part".into()))]
+    #[test_case("--format yaml", ArgsError::InvalidValue { flag: "--format", value: "yaml".into() })]
+    #[test_case("check --part 2", ArgsError::UnknownFlag("--part".into()))]
+    #[test_case("--jobs 0", ArgsError::InvalidValue { flag: "--jobs", value: "0".into() })]
+    #[test_case("bench -n lots", ArgsError::InvalidValue { flag: "--iterations", value: "lots".into() })]
+    #[test_case("bench -n 0", ArgsError::InvalidValue { flag: "--iterations", value: "0".into() })]
+    #[test_case("bench 1 --top 3 --iterations=0", ArgsError::InvalidValue { flag: "--iterations", value: "0".into() })]
+    #[test_case("--inputs -", ArgsError::StdinNeedsOneDay)]
+    #[test_case("check 3..=4 -i -", ArgsError::StdinNeedsOneDay)]
+    #[test_case("7 --inputs - --set alice", ArgsError::NotWithStdin("--set"))]
+    #[test_case("7 --example one -i -", ArgsError::NotWithStdin("--example"))]
+    #[test_case("bench --example one", ArgsError::UnknownFlag("--example".into()))]
+    #[test_case("fetch 7 -i -", ArgsError::NotWithStdin("fetch"))]
+    #[test_case("fetch 26", ArgsError::UnknownDay { day: 26, available: 25 })]
+    #[test_case("fetch 1 --delay -1", ArgsError::InvalidValue { flag: "--delay", value: "-1".into() })]
+    #[test_case("submit", ArgsError::MissingArgument("DAY"))]
+    #[test_case("submit 7", ArgsError::MissingArgument("PART"))]
+    #[test_case("submit 11 1", ArgsError::UnknownDay { day: 11, available: 10 })]
+    #[test_case("submit 7 3", ArgsError::InvalidPart("3".into()))]
+    #[test_case("submit 7 1 2", ArgsError::UnexpectedArgument("2".into()))]
+    #[test_case("submit 7 1 -i -", ArgsError::NotWithStdin("submit"))]
+    #[test_case("new", ArgsError::MissingArgument("DAY"))]
+    #[test_case("new 26", ArgsError::UnknownDay { day: 26, available: 25 })]
+    #[test_case("new 11 -i -", ArgsError::NotWithStdin("new"))]
+    #[test_case("watch", ArgsError::MissingArgument("DAY"))]
+    #[test_case("watch 11", ArgsError::UnknownDay { day: 11, available: 10 })]
+    #[test_case("watch 7 -i -", ArgsError::NotWithStdin("watch"))]
+    #[test_case("--top 3", ArgsError::OnlyForDay("--top", 1))]
+    #[test_case("1..=2 --top 3", ArgsError::OnlyForDay("--top", 1))]
+    #[test_case("1 --top 0", ArgsError::InvalidValue { flag: "--top", value: "0".into() })]
+    #[test_case("1 --top 3 --part 2", ArgsError::Conflict("--top", "--part"))]
+    #[test_case("bench --top 3", ArgsError::OnlyForDay("--top", 1))]
+    #[test_case("--rules custom.txt", ArgsError::OnlyForDay("--rules", 2))]
+    #[test_case("1..=2 --rules custom.txt", ArgsError::OnlyForDay("--rules", 2))]
+    #[test_case("1 --top 3 --rules custom.txt", ArgsError::OnlyForDay("--rules", 2))]
+    #[test_case("bench 2 --rules custom.txt", ArgsError::UnknownFlag("--rules".into()))]
+    #[test_case("bench 1 --jobs 3", ArgsError::UnknownFlag("--jobs".into()))]
+    #[test_case("gen", ArgsError::MissingArgument("DAY"))]
+    #[test_case("gen 11", ArgsError::UnknownDay { day: 11, available: 10 })]
+    #[test_case("gen 3 --seed -1", ArgsError::InvalidValue { flag: "--seed", value: "-1".into() })]
+    #[test_case("gen 3 -i -", ArgsError::UnknownFlag("-i".into()))]
+    #[test_case("inventory 1", ArgsError::UnexpectedArgument("1".into()))]
+    #[test_case("inventory --format csv", ArgsError::InvalidValue { flag: "--format", value: "csv".into() })]
+    #[test_case("inventory -e one -i -", ArgsError::NotWithStdin("--example"))]
+    fn test_errors(args: &str, expected: ArgsError) {
+        assert_eq!(parse(args), Err(expected));
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const YEAR: usize = 2022;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable that overrides [`DEFAULT_BASE_URL`]
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the `session` cookie from a logged in browser
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// File in an inputs directory that can hold the session token instead, so
+/// each input set can use its owner's token
+pub const SESSION_FILE: &str = ".session";
+
+/// File in an inputs directory recording when the last request was made
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+const USER_AGENT: &str = "github.com/sgrowe/advent-of-code-2022";
+
+/// Makes requests to the Advent of Code site as the owner of an inputs
+/// directory, spacing them out so the site isn't hammered
+pub struct Client {
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// A client for the inputs in `dir`, taking the session token from the
+    /// environment or the directory's `.session` file, and waiting at least
+    /// `delay` between requests
+    pub fn new(dir: &Path, base_url: &str, delay: Duration) -> Result<Self, ClientError> {
+        let session = match std::env::var(SESSION_VAR) {
+            Ok(session) => session,
+            Err(_) => {
+                let path = dir.join(SESSION_FILE);
+
+                fs::read_to_string(&path).map_err(|e| match e.kind() {
+                    io::ErrorKind::NotFound => ClientError::NoSession(path.clone()),
+                    _ => ClientError::Read(path.clone(), e),
+                })?
+            }
+        };
+
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Ok(Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            throttle: Throttle {
+                stamp: dir.join(LAST_REQUEST_FILE),
+                delay,
+            },
+            agent,
+        })
+    }
+
+    /// The body of `path` within this year's event, such as `day/7/input`
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        self.send("GET", path, None)
+    }
+
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        self.send("POST", path, Some(form))
+    }
+
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{}/{}", self.base_url, YEAR, path);
+
+        self.throttle.wait()?;
+
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session));
+
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| ClientError::Http(url, e.to_string())),
+            Err(ureq::Error::Status(400, _)) => Err(ClientError::BadSession),
+            Err(ureq::Error::Status(status, _)) => Err(ClientError::Status(url, status)),
+            Err(ureq::Error::Transport(e)) => Err(ClientError::Http(url, e.to_string())),
+        }
+    }
+}
+
+/// Keeps requests at least `delay` apart, even across separate runs, by
+/// recording the time of the last one in a `stamp` file
+struct Throttle {
+    stamp: PathBuf,
+    delay: Duration,
+}
+
+impl Throttle {
+    fn wait(&self) -> Result<(), ClientError> {
+        let last = match fs::read_to_string(&self.stamp) {
+            Ok(stamp) => stamp.trim().parse().ok(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(ClientError::Read(self.stamp.clone(), e)),
+        };
+
+        let last = last.map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(elapsed) = last.and_then(|last| SystemTime::now().duration_since(last).ok()) {
+            thread::sleep(self.delay.saturating_sub(elapsed));
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        fs::write(&self.stamp, now.as_millis().to_string())
+            .map_err(|e| ClientError::Write(self.stamp.clone(), e))
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession(PathBuf),
+    BadSession,
+    NotUnlocked { day: usize, wait: Option<Duration> },
+    Status(String, u16),
+    Http(String, String),
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession(path) => write!(
+                f,
+                "no session token, set {} or save it in `{}`",
+                SESSION_VAR,
+                path.display()
+            ),
+            ClientError::BadSession => {
+                write!(f, "the session token was rejected, it may have expired")
+            }
+            ClientError::NotUnlocked {
+                day,
+                wait: Some(wait),
+            } => write!(
+                f,
+                "day {} isn't unlocked yet, it unlocks in {}",
+                day,
+                FormatWait(*wait)
+            ),
+            ClientError::NotUnlocked { day, wait: None } => {
+                write!(f, "day {} isn't unlocked yet", day)
+            }
+            ClientError::Status(url, status) => {
+                write!(f, "request to {} failed with status {}", url, status)
+            }
+            ClientError::Http(url, e) => write!(f, "request to {} failed: {}", url, e),
+            ClientError::Read(path, e) => write!(f, "couldn't read `{}`: {}", path.display(), e),
+            ClientError::Write(path, e) => write!(f, "couldn't write `{}`: {}", path.display(), e),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+/// Formats a wait as days, hours, minutes and seconds, leaving out the
+/// leading units that are zero
+pub struct FormatWait(pub Duration);
+
+impl Display for FormatWait {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let secs = self.0.as_secs();
+
+        let (days, hours, mins, secs) =
+            (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+        if days > 0 {
+            write!(f, "{}d {}h {}m {}s", days, hours, mins, secs)
+        } else if hours > 0 {
+            write!(f, "{}h {}m {}s", hours, mins, secs)
+        } else if mins > 0 {
+            write!(f, "{}m {}s", mins, secs)
+        } else {
+            write!(f, "{}s", secs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(59, "59s")]
+    #[test_case(3 * 3600 + 5, "3h 0m 5s")]
+    #[test_case(2 * 86_400 + 61, "2d 0h 1m 1s")]
+    fn test_format_wait(secs: u64, expected: &str) {
+        assert_eq!(FormatWait(Duration::from_secs(secs)).to_string(), expected);
+    }
+}
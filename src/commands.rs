@@ -0,0 +1,822 @@
+//! What each of the runner's commands does. Every command writes its output
+//! to `out`, and its warnings and the errors it carries on past to `err`, so
+//! the binary only has to parse the arguments and pick an exit code.
+
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead, BufWriter, Cursor, Write},
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process, thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{KnownAnswers, Verdict},
+    bench::{self, FormatDuration, Stats},
+    check::{self, Outcome, Summary},
+    client::{Client, FormatWait},
+    day::Answers,
+    day_01,
+    day_02::Rules,
+    examples::{Example, EXAMPLES_DIR},
+    fetch::{self, Fetched},
+    generate,
+    input::{normalise, Inputs},
+    inventory::Report,
+    parallel, scaffold,
+    solution::{Format, Part, Solution, Timings, CSV_HEADER},
+    submit::{self, Response},
+    watch::{self, Watcher},
+    DAYS,
+};
+
+/// Why a command didn't succeed
+#[derive(Debug)]
+pub enum CommandError {
+    /// The command stopped because of this error
+    Error(Box<dyn Error>),
+    /// The command finished, but something it has already reported failed
+    Failed,
+}
+
+impl<E: Error + 'static> From<E> for CommandError {
+    fn from(e: E) -> Self {
+        CommandError::Error(Box::new(e))
+    }
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::Error(e) => write!(f, "{}", e),
+            CommandError::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// A [`CommandError`] with just a message
+fn error(message: impl Into<String>) -> CommandError {
+    CommandError::Error(message.into().into())
+}
+
+type CommandResult = Result<(), CommandError>;
+
+/// Solves the `days` from their inputs, or from the named example, on up to
+/// `jobs` threads. Carries on past days that fail, but fails at the end if
+/// any did.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    days: &[usize],
+    part: Option<Part>,
+    format: Format,
+    jobs: Option<NonZeroUsize>,
+    example: Option<&str>,
+    inputs: &Inputs,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> CommandResult {
+    let started = Instant::now();
+
+    let results = parallel::map_ordered(days, jobs.map_or(1, NonZeroUsize::get), |&day| {
+        solve_day(day, part, example, inputs)
+    });
+
+    let wall_clock = started.elapsed();
+
+    match format {
+        Format::Text => {}
+        Format::Json => writeln!(out, "[")?,
+        Format::Csv => writeln!(out, "{}", CSV_HEADER)?,
+    }
+
+    let solved = results.iter().filter(|r| matches!(r, Ok(Ok(_)))).count();
+
+    let mut printed = 0;
+    let mut summed = Duration::ZERO;
+
+    for (&day, result) in days.iter().zip(results) {
+        let (solution, timings, example) = match result {
+            Ok(Ok(solved)) => solved,
+            Ok(Err(e)) => {
+                writeln!(err, "error: {}", e)?;
+                continue;
+            }
+            Err(panic) => {
+                writeln!(err, "error: day {} panicked: {}", day, panic)?;
+                continue;
+            }
+        };
+
+        printed += 1;
+        summed += timings.total();
+
+        if let Some(example) = &example {
+            for (part, expected, _) in example.mismatches(&solution) {
+                writeln!(
+                    err,
+                    "warning: day {} part {} should be {} for example `{}`",
+                    day, part, expected, example.name
+                )?;
+            }
+        }
+
+        match format {
+            Format::Text => write!(out, "\n\n-- Day {} --\n{}", day, solution.to_text())?,
+            Format::Json => {
+                let separator = if printed < solved { "," } else { "" };
+
+                writeln!(
+                    out,
+                    "  {}{}",
+                    solution.to_json(day, Some(&timings)),
+                    separator
+                )?;
+            }
+            Format::Csv => writeln!(out, "{}", solution.to_csv(day, Some(&timings)))?,
+        }
+    }
+
+    if format == Format::Json {
+        writeln!(out, "]")?;
+    }
+
+    if jobs.is_some() {
+        let report = format!(
+            "Solved {} of {} days in {} wall-clock, {} summed across days",
+            solved,
+            days.len(),
+            FormatDuration(wall_clock),
+            FormatDuration(summed)
+        );
+
+        // Keep the machine readable formats parseable
+        match format {
+            Format::Text => writeln!(out, "\n{}", report)?,
+            Format::Json | Format::Csv => writeln!(err, "{}", report)?,
+        }
+    }
+
+    if solved < days.len() {
+        return Err(CommandError::Failed);
+    }
+
+    Ok(())
+}
+
+/// Solves `day` from its input, or from the named example if there is one
+fn solve_day(
+    day: usize,
+    part: Option<Part>,
+    example: Option<&str>,
+    inputs: &Inputs,
+) -> Result<(Answers, Timings, Option<Example>), String> {
+    let example = example
+        .map(|name| Example::load(Path::new(EXAMPLES_DIR), day, name))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    let input = match &example {
+        Some(example) => normalise(&example.input),
+        None => inputs.read_normalised(day).map_err(|e| e.to_string())?,
+    };
+
+    let (solution, timings) = DAYS[day - 1]
+        .solve(&input, part)
+        .map_err(|e| e.to_string())?;
+
+    Ok((solution, timings, example))
+}
+
+/// Lists the `n` biggest calorie counts from day 1's input or example. The
+/// input is read a line at a time, so it can be far bigger than memory,
+/// unless it's split across `jobs` threads.
+pub fn top(
+    n: usize,
+    format: Format,
+    jobs: Option<NonZeroUsize>,
+    example: Option<&str>,
+    inputs: &Inputs,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> CommandResult {
+    let top = if let Some(jobs) = jobs {
+        let input = match example {
+            Some(_) => read_input_or_example(inputs, 1, example)?,
+            None => inputs.read_decompressed(1)?,
+        };
+
+        day_01::top_n_parallel(&input, n, jobs.get()).map_err(|e| e.in_day(1))?
+    } else {
+        let reader: Box<dyn BufRead> = match example {
+            Some(_) => Box::new(Cursor::new(read_input_or_example(inputs, 1, example)?)),
+            None => inputs.open(1)?,
+        };
+
+        day_01::top_n_from_reader(reader, n).map_err(|e| e.in_day(1))?
+    };
+    let total: usize = top.iter().sum();
+
+    if top.len() < n {
+        writeln!(err, "warning: there are only {} elves", top.len())?;
+    }
+
+    match format {
+        Format::Text => {
+            writeln!(out, "\n\n-- Day 1: the top {} elves --", n)?;
+
+            for (rank, calories) in top.iter().enumerate() {
+                writeln!(out, "{:>4}. {}", rank + 1, calories)?;
+            }
+
+            writeln!(out, "Total: {}", total)?;
+        }
+        Format::Json => {
+            let top: Vec<String> = top.iter().map(ToString::to_string).collect();
+
+            writeln!(
+                out,
+                "{{\"day\":1,\"top\":[{}],\"total\":{}}}",
+                top.join(","),
+                total
+            )?;
+        }
+        Format::Csv => {
+            writeln!(out, "rank,calories")?;
+
+            for (rank, calories) in top.iter().enumerate() {
+                writeln!(out, "{},{}", rank + 1, calories)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Solves day 2's input or example playing by the rules in `path` rather than
+/// rock, paper, scissors
+pub fn play(
+    path: &Path,
+    part: Option<Part>,
+    format: Format,
+    example: Option<&str>,
+    inputs: &Inputs,
+    out: &mut dyn Write,
+) -> CommandResult {
+    let rules = Rules::load(path)?;
+
+    let input = read_input_or_example(inputs, 2, example)?;
+
+    let answers = rules.play(&input).map_err(|e| e.in_day(2))?;
+
+    let solution = Solution {
+        part_one: (part != Some(Part::Two)).then_some(answers.part_one),
+        part_two: (part != Some(Part::One)).then_some(answers.part_two),
+    };
+
+    match format {
+        Format::Text => write!(
+            out,
+            "\n\n-- Day 2: {} --\n{}",
+            rules.shapes().join(", "),
+            solution.to_text()
+        )?,
+        Format::Json => writeln!(out, "{}", solution.to_json(2, None))?,
+        Format::Csv => writeln!(out, "{}\n{}", CSV_HEADER, solution.to_csv(2, None))?,
+    }
+
+    Ok(())
+}
+
+/// Reports on every elf's items from day 1's input or example
+pub fn inventory(
+    format: Format,
+    example: Option<&str>,
+    inputs: &Inputs,
+    out: &mut dyn Write,
+) -> CommandResult {
+    let input = read_input_or_example(inputs, 1, example)?;
+
+    let elves = day_01::elves(&input)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.in_day(1))?;
+
+    let report = Report::new(elves);
+
+    match format {
+        Format::Text => write!(out, "{}", report.to_text())?,
+        Format::Json => writeln!(out, "{}", report.to_json())?,
+        Format::Csv => unreachable!("the arguments were checked"),
+    }
+
+    Ok(())
+}
+
+/// Times parsing and each part of the `days`
+pub fn bench(
+    days: &[usize],
+    iterations: usize,
+    warmup: usize,
+    inputs: &Inputs,
+    out: &mut dyn Write,
+) -> CommandResult {
+    writeln!(
+        out,
+        "Timing {} iterations per day after {} warmup runs\n",
+        iterations, warmup
+    )?;
+
+    write!(out, "{}", bench::header())?;
+
+    let mut grand_total = Stats::default();
+
+    for &day in days {
+        let input = inputs.read_normalised(day)?;
+        let stats = bench::bench_day(DAYS[day - 1], &input, iterations, warmup)?;
+
+        write!(out, "{}", bench::day_rows(day, &stats))?;
+
+        grand_total = grand_total + stats.total;
+    }
+
+    write!(out, "{}", bench::grand_total_row(grand_total))?;
+
+    Ok(())
+}
+
+/// Times the ways of finding day 1's `n` biggest calorie counts against each
+/// other, failing if they disagree
+pub fn bench_top(
+    n: usize,
+    jobs: Option<NonZeroUsize>,
+    iterations: usize,
+    warmup: usize,
+    inputs: &Inputs,
+    out: &mut dyn Write,
+) -> CommandResult {
+    let jobs = jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+
+    writeln!(
+        out,
+        "Timing {} iterations of day 1's top {} after {} warmup runs\n",
+        iterations, n, warmup
+    )?;
+
+    write!(out, "{}", bench::header())?;
+
+    let input = inputs.read_normalised(1)?;
+    let stats = bench::bench_top_n(&input, n, jobs, iterations, warmup).map_err(|e| e.in_day(1))?;
+
+    write!(out, "{}", bench::top_n_rows(jobs, &stats))?;
+
+    if !stats.agree {
+        return Err(error(
+            "the ways of finding the top elves found different counts",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Compares the `days`' answers against the known answers, failing if any of
+/// them differ or a day can't be solved
+pub fn check(
+    days: &[usize],
+    inputs: &Inputs,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> CommandResult {
+    let known = match inputs.answers_path() {
+        Some(path) => KnownAnswers::load(&path)?,
+        None => KnownAnswers::default(),
+    };
+
+    let mut summary = Summary::default();
+
+    for &day in days {
+        let solved = inputs
+            .read_normalised(day)
+            .map_err(|e| e.to_string())
+            .and_then(|input| DAYS[day - 1].solve(&input, None).map_err(|e| e.to_string()));
+
+        // Carry on with the other days, counting both of this one's parts as
+        // failures
+        let (solution, _) = match solved {
+            Ok(solved) => solved,
+            Err(e) => {
+                writeln!(err, "error: {}", e)?;
+                summary.failed += Part::BOTH.len();
+                continue;
+            }
+        };
+
+        let answers = [solution.part_one, solution.part_two];
+
+        for (part, actual) in Part::BOTH.into_iter().zip(answers) {
+            let Some(actual) = actual else {
+                continue;
+            };
+
+            let outcome = Outcome::of(&known, day, part, &actual);
+
+            write!(
+                out,
+                "{}",
+                check::format_outcome(day, part, &actual, &outcome)
+            )?;
+
+            summary.add(&outcome);
+        }
+    }
+
+    writeln!(out, "\n{}", summary)?;
+
+    if summary.failed > 0 {
+        return Err(CommandError::Failed);
+    }
+
+    Ok(())
+}
+
+/// Downloads the `days`' inputs that aren't already saved
+pub fn fetch(
+    days: &[usize],
+    inputs: &Inputs,
+    base_url: &str,
+    delay: Duration,
+    out: &mut dyn Write,
+) -> CommandResult {
+    let Inputs::Dir(dir) = inputs else {
+        return Err(error("inputs can't be fetched to stdin"));
+    };
+
+    let client = Client::new(dir, base_url, delay)?;
+
+    for &day in days {
+        match fetch::fetch(&client, dir, day)? {
+            Fetched::Downloaded(path) => writeln!(out, "Day {}: saved to {}", day, path.display())?,
+            Fetched::Cached(path) => writeln!(out, "Day {}: already have {}", day, path.display())?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Submits `answer`, or else `day`'s answer to `part`, recording the verdict.
+/// Fails unless the answer was right.
+pub fn submit(
+    day: usize,
+    part: Part,
+    answer: Option<String>,
+    inputs: &Inputs,
+    base_url: &str,
+    delay: Duration,
+    out: &mut dyn Write,
+) -> CommandResult {
+    let Inputs::Dir(dir) = inputs else {
+        return Err(error("answers can't be recorded when reading from stdin"));
+    };
+
+    let answers_path = inputs.answers_path().unwrap();
+
+    let mut known = KnownAnswers::load(&answers_path)?;
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let input = inputs.read_normalised(day)?;
+            let (solution, _) = DAYS[day - 1].solve(&input, Some(part))?;
+
+            let answer = match part {
+                Part::One => solution.part_one,
+                Part::Two => solution.part_two,
+            };
+
+            answer.unwrap()
+        }
+    };
+
+    if answer.contains('\n') {
+        return Err(error(format!(
+            "the answer spans several lines, read it and pass it with --answer:\n{}",
+            answer
+        )));
+    }
+
+    if let Some(reason) = known.reason_to_refuse(day, part, &answer) {
+        return Err(error(format!("not submitting, {}", reason)));
+    }
+
+    let client = Client::new(dir, base_url, delay)?;
+
+    let response = submit::submit(&client, day, part, &answer)?;
+
+    if let Some(verdict) = response.verdict() {
+        known.record(&answers_path, day, part, &answer, verdict)?;
+    }
+
+    match response {
+        Response::Correct => writeln!(
+            out,
+            "`{}` is the right answer! Saved to {}",
+            answer,
+            answers_path.display()
+        )?,
+        Response::TooHigh => writeln!(out, "`{}` is too high", answer)?,
+        Response::TooLow => writeln!(out, "`{}` is too low", answer)?,
+        Response::Wrong => writeln!(out, "`{}` isn't the right answer", answer)?,
+        Response::Wait(wait) => writeln!(
+            out,
+            "An answer was submitted too recently, wait {} before trying again",
+            FormatWait(wait)
+        )?,
+        Response::AlreadySolved => writeln!(
+            out,
+            "Day {} part {} is already solved, or the part before it isn't yet",
+            day, part
+        )?,
+    }
+
+    if response.verdict() != Some(Verdict::Correct) {
+        return Err(CommandError::Failed);
+    }
+
+    Ok(())
+}
+
+/// Starts `day` in the crate at `root`, see [`scaffold::scaffold`]
+pub fn new(root: &Path, day: usize, inputs: &Inputs, out: &mut dyn Write) -> CommandResult {
+    let Inputs::Dir(dir) = inputs else {
+        return Err(error("an input file can't be created on stdin"));
+    };
+
+    let scaffolded = scaffold::scaffold(root, dir, day, DAYS.len())?;
+
+    writeln!(out, "Created {}", scaffolded.module.display())?;
+    writeln!(
+        out,
+        "Registered day {} in {}",
+        day,
+        scaffolded.lib.display()
+    )?;
+
+    if let Some(input) = scaffolded.input {
+        writeln!(out, "Created an empty {}", input.display())?;
+    }
+
+    if let Some(example) = scaffolded.example {
+        writeln!(out, "Created an empty example at {}", example.display())?;
+    }
+
+    Ok(())
+}
+
+/// Solves `day` whenever its input changes, and rebuilds the crate at `root`
+/// and restarts whenever its source does. Only returns if it can't carry on.
+pub fn watch(
+    root: &Path,
+    day: usize,
+    inputs: &Inputs,
+    color: bool,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
+) -> CommandResult {
+    let source = root.join("src").join(format!("day_{:02}.rs", day));
+    let input = inputs.path(day).unwrap();
+
+    // Found before any rebuild can replace the file it points to
+    let exe = env::current_exe()?;
+    let bin = exe.file_stem().unwrap_or_default().to_string_lossy();
+
+    let mut watcher = Watcher::new(&[&source, &input]);
+
+    let mut previous = env::var(watch::PREVIOUS_VAR)
+        .ok()
+        .map(|encoded| watch::decode_previous(day, &encoded));
+
+    writeln!(out, "Watching {} and {}", source.display(), input.display())?;
+
+    let mut reason = match previous {
+        Some(_) => "rebuilt".to_owned(),
+        None => "started".to_owned(),
+    };
+
+    loop {
+        writeln!(out, "\n-- Day {} ({}) --", day, reason)?;
+
+        match solve_watched(day, inputs) {
+            Ok(answers) => {
+                write!(
+                    out,
+                    "{}",
+                    watch::render_changes(&answers, previous.as_ref(), color)
+                )?;
+
+                previous = Some(answers);
+            }
+            Err(e) => writeln!(err, "error: {}", e)?,
+        }
+
+        let changed = loop {
+            thread::sleep(watch::POLL_INTERVAL);
+
+            let changed = watcher.poll();
+
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        if changed.contains(&source) {
+            writeln!(out, "\n{} changed, rebuilding", source.display())?;
+
+            if watch::rebuild(root, &bin) {
+                let mut command = process::Command::new(&exe);
+
+                command.args(env::args_os().skip(1));
+
+                if let Some(answers) = &previous {
+                    command.env(watch::PREVIOUS_VAR, watch::encode_previous(day, answers));
+                }
+
+                return Err(watch::reexec(command).into());
+            }
+
+            writeln!(out, "The build failed, still watching")?;
+        }
+
+        reason = changed
+            .iter()
+            .map(|p| format!("{} changed", p.display()))
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+}
+
+/// Solves `day`, turning a panic into an error so watching can carry on
+fn solve_watched(day: usize, inputs: &Inputs) -> Result<Answers, String> {
+    let input = inputs.read_normalised(day).map_err(|e| e.to_string())?;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| DAYS[day - 1].solve(&input, None))) {
+        Ok(Ok((answers, _))) => Ok(answers),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(format!("day {} panicked", day)),
+    }
+}
+
+/// Writes a random input for `day`, see [`generate::generate`]
+pub fn gen(day: usize, size: usize, seed: u64, out: &mut dyn Write) -> CommandResult {
+    let mut out = BufWriter::new(out);
+
+    let written = generate::generate(day, size, seed, &mut out).and_then(|()| out.flush());
+
+    match written {
+        // Stopping early is fine when piped into something like `head`
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Reads the named example if there is one, or else the input
+fn read_input_or_example(
+    inputs: &Inputs,
+    day: usize,
+    example: Option<&str>,
+) -> Result<String, CommandError> {
+    match example {
+        Some(name) => Ok(normalise(
+            &Example::load(Path::new(EXAMPLES_DIR), day, name)?.input,
+        )),
+        None => Ok(inputs.read_normalised(day)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    /// Runs `command`, returning whether it succeeded along with its output
+    /// and errors
+    fn capture<F>(command: F) -> (CommandResult, String, String)
+    where
+        F: FnOnce(&mut dyn Write, &mut dyn Write) -> CommandResult,
+    {
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+
+        let result = command(&mut out, &mut err);
+
+        (
+            result,
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_run_example() {
+        let (result, out, err) = capture(|out, err| {
+            let example = Some("one");
+
+            run(
+                &[1],
+                None,
+                Format::Text,
+                None,
+                example,
+                &Inputs::Stdin,
+                out,
+                err,
+            )
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(out, "\n\n-- Day 1 --\nPart one: 24000\nPart two: 45000\n");
+        assert_eq!(err, "");
+    }
+
+    #[test]
+    fn test_run_fails_after_reporting_a_missing_example() {
+        let (result, out, err) = capture(|out, err| {
+            let example = Some("missing");
+
+            run(
+                &[1],
+                None,
+                Format::Csv,
+                None,
+                example,
+                &Inputs::Stdin,
+                out,
+                err,
+            )
+        });
+
+        assert!(matches!(result, Err(CommandError::Failed)));
+        assert_eq!(out, format!("{}\n", CSV_HEADER));
+        assert!(err.starts_with("error: day 1 has no example called `missing`"));
+    }
+
+    #[test]
+    fn test_top() {
+        let (result, out, err) =
+            capture(|out, err| top(7, Format::Csv, None, Some("one"), &Inputs::Stdin, out, err));
+
+        assert!(result.is_ok());
+        assert_eq!(
+            out,
+            "rank,calories\n1,24000\n2,11000\n3,10000\n4,6000\n5,4000\n"
+        );
+        assert_eq!(err, "warning: there are only 5 elves\n");
+    }
+
+    #[test]
+    fn test_play() {
+        let rules = Path::new("rules/rock_paper_scissors.txt");
+
+        let (result, out, _) =
+            capture(|out, _| play(rules, None, Format::Json, Some("one"), &Inputs::Stdin, out));
+
+        assert!(result.is_ok());
+        assert_eq!(out, "{\"day\":2,\"part_one\":\"15\",\"part_two\":\"12\"}\n");
+    }
+
+    #[test]
+    fn test_check_fails_on_a_wrong_answer() {
+        let dir = env::temp_dir().join(format!("aoc-2022-commands-{}", process::id()));
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy("examples/day_01/one.txt", dir.join("day_01.txt")).unwrap();
+        fs::write(dir.join("answers.txt"), "1.1: 24000\n1.2: 45001\n").unwrap();
+
+        let (result, out, _) = capture(|out, err| check(&[1], &Inputs::Dir(dir.clone()), out, err));
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(result, Err(CommandError::Failed)));
+        assert_eq!(
+            out,
+            "Day  1 part 1: pass\n\
+             Day  1 part 2: FAIL\n    - 45001\n    + 45000\n\
+             \n1 passed, 1 failed, 0 unknown\n"
+        );
+    }
+
+    #[test]
+    fn test_fetch_needs_a_directory() {
+        let (result, out, _) =
+            capture(|out, _| fetch(&[1], &Inputs::Stdin, "", Duration::ZERO, out));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "inputs can't be fetched to stdin"
+        );
+        assert_eq!(out, "");
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::ParseError,
+    solution::{Part, Solution, Timings},
+};
+
+/// A single day's puzzle, split into parsing and the two parts so each can be
+/// run (and timed) on its own.
+pub trait Day {
+    const DAY: usize;
+
+    /// The parsed puzzle input, which may borrow from the raw input text
+    type Parsed<'a>;
+
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part_one(parsed: &Self::Parsed<'_>) -> Self::PartOne;
+
+    fn part_two(parsed: &Self::Parsed<'_>) -> Self::PartTwo;
+
+    /// Parses the input and solves both parts
+    fn solution(input: &str) -> Result<Solution<Self::PartOne, Self::PartTwo>, ParseError> {
+        let parsed = Self::parse(input).map_err(|e| e.in_day(Self::DAY))?;
+
+        Ok(Solution {
+            part_one: Self::part_one(&parsed),
+            part_two: Self::part_two(&parsed),
+        })
+    }
+}
+
+/// A day's answers formatted as strings, `None` for a part that wasn't run
+pub type Answers = Solution<Option<String>, Option<String>>;
+
+/// Object safe version of [`Day`] with the answers formatted as strings, so
+/// that every day can sit in one registry.
+pub trait Solver {
+    /// Solves the requested part, or both parts if `part` is `None`, timing
+    /// parsing and each part separately. A part that wasn't asked for is
+    /// never computed.
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<(Answers, Timings), ParseError>;
+}
+
+impl<D: Day> Solver for D {
+    fn solve(&self, input: &str, part: Option<Part>) -> Result<(Answers, Timings), ParseError> {
+        let start = Instant::now();
+        let parsed = D::parse(input).map_err(|e| e.in_day(D::DAY))?;
+        let parse = start.elapsed();
+
+        let (part_one, part_one_time) = timed(part != Some(Part::Two), || D::part_one(&parsed));
+        let (part_two, part_two_time) = timed(part != Some(Part::One), || D::part_two(&parsed));
+
+        let solution = Solution {
+            part_one: part_one.map(|a| a.to_string()),
+            part_two: part_two.map(|b| b.to_string()),
+        };
+
+        let timings = Timings {
+            parse,
+            part_one: part_one_time,
+            part_two: part_two_time,
+        };
+
+        Ok((solution, timings))
+    }
+}
+
+/// Runs `f` if `run` is set, returning its result and how long it took
+fn timed<T>(run: bool, f: impl FnOnce() -> T) -> (Option<T>, Option<Duration>) {
+    if !run {
+        return (None, None);
+    }
+
+    let start = Instant::now();
+    let result = f();
+
+    (Some(result), Some(start.elapsed()))
+}
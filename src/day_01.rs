@@ -1,120 +1,466 @@
-use crate::solution::Solution;
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead, iter::FusedIterator};
 
-pub fn main(input: &str) {
-    solution(input).print();
+use crate::{
+    day::Day,
+    error::{ParseError, ReadError},
+    groups_of::split_on_blank_lines,
+    parallel,
+    utils::parse_as,
+};
+
+pub struct Day01;
+
+impl Day for Day01 {
+    const DAY: usize = 1;
+
+    type Parsed<'a> = Vec<usize>;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        elves(input).map(|elf| elf.map(|elf| elf.total)).collect()
+    }
+
+    fn part_one(calories: &Self::Parsed<'_>) -> Self::PartOne {
+        calories.iter().copied().max().unwrap_or(0)
+    }
+
+    fn part_two(calories: &Self::Parsed<'_>) -> Self::PartTwo {
+        top_n(calories.iter().copied(), 3).into_iter().sum()
+    }
 }
 
-fn solution(input: &str) -> Solution<usize, usize> {
-    let top_three = top_three_calorie_counts(input);
+/// The `n` biggest calorie counts, biggest first. There are fewer than `n`
+/// when there are fewer than `n` elves.
+pub fn top_n<I>(calories: I, n: usize) -> Vec<usize>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let calories = calories.into_iter();
+
+    // A min-heap of the biggest counts so far, so the smallest of them is the
+    // one to drop. `n` comes from the user, so it's no guide to how much room
+    // the heap needs.
+    let mut top = BinaryHeap::with_capacity(n.min(calories.size_hint().0));
 
-    Solution {
-        part_one: top_three[0],
-        part_two: top_three.into_iter().sum::<usize>(),
+    for c in calories {
+        if top.len() < n {
+            top.push(Reverse(c));
+        } else if top.peek().is_some_and(|&Reverse(smallest)| c > smallest) {
+            top.pop();
+            top.push(Reverse(c));
+        }
     }
+
+    top.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(c)| c)
+        .collect()
 }
 
-fn top_three_calorie_counts(input: &str) -> [usize; 3] {
-    let mut top_three = [0; 3];
+/// What one elf carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// The elf's position in the input, from 1
+    pub number: usize,
+    pub items: usize,
+    pub total: usize,
+    pub largest: usize,
+    pub smallest: usize,
+}
 
-    for t in calories_per_elf(input) {
-        if t <= top_three[2] {
-            continue;
+impl Elf {
+    fn empty_handed(number: usize) -> Self {
+        Elf {
+            number,
+            items: 0,
+            total: 0,
+            largest: 0,
+            smallest: usize::MAX,
         }
+    }
+
+    /// Adds the item on line `i` of the input, checking the running `total`
+    /// of every elf's calories can't overflow
+    fn add_item(&mut self, total: &mut usize, i: usize, l: &str) -> Result<(), ParseError> {
+        let calories: usize = parse_as(l, l, "a calorie count").map_err(|e| e.on_line(i))?;
+
+        *total = total
+            .checked_add(calories)
+            .ok_or_else(|| ParseError::new(l, l, "fewer calories in total").on_line(i))?;
+
+        self.items += 1;
+        self.total += calories;
+        self.largest = self.largest.max(calories);
+        self.smallest = self.smallest.min(calories);
+
+        Ok(())
+    }
+}
+
+/// Every elf's items, from blocks of calorie counts separated by blank lines
+pub fn elves(input: &str) -> impl Iterator<Item = Result<Elf, ParseError>> + '_ {
+    // Every elf's calories together, so that no sum of elves' totals can
+    // overflow
+    let mut total = 0usize;
 
-        let mut i = 0;
+    split_on_blank_lines(input)
+        .enumerate()
+        .map(move |(n, block)| {
+            if block.text.is_empty() {
+                return Err(ParseError::end_of_line("", "a calorie count").on_line(block.line));
+            }
+
+            let mut elf = Elf::empty_handed(n + 1);
+
+            for (i, l) in block.lines() {
+                elf.add_item(&mut total, i, l)?;
+            }
 
-        while i < top_three.len() {
-            if t > top_three[i] {
-                let mut j = top_three.len() - 1;
+            Ok(elf)
+        })
+}
 
-                while j > i {
-                    top_three[j] = top_three[j - 1];
-                    j -= 1;
-                }
+/// Every elf's items like [`elves`] gives for the [`normalise`]d input, but
+/// read a line at a time from `reader` so that inputs of any size can be
+/// read in a constant amount of memory
+///
+/// [`normalise`]: crate::input::normalise
+pub fn elves_from_reader<R: BufRead>(reader: R) -> ElvesFromReader<R> {
+    ElvesFromReader {
+        reader,
+        line: String::new(),
+        line_no: 0,
+        elves: 0,
+        total: 0,
+        done: false,
+    }
+}
 
-                top_three[i] = t;
+pub struct ElvesFromReader<R> {
+    reader: R,
+    /// The line being read, kept to reuse its allocation
+    line: String,
+    line_no: usize,
+    elves: usize,
+    total: usize,
+    /// Set once the input ends or fails
+    done: bool,
+}
+
+impl<R: BufRead> ElvesFromReader<R> {
+    fn next_elf(&mut self) -> Result<Option<Elf>, ReadError> {
+        let mut elf = Elf::empty_handed(self.elves + 1);
+
+        loop {
+            self.line.clear();
+
+            if self.reader.read_line(&mut self.line)? == 0 {
+                break;
+            }
+
+            self.line_no += 1;
+
+            let mut line = self.line.as_str();
+
+            if self.line_no == 1 {
+                line = line.strip_prefix('\u{feff}').unwrap_or(line);
+            }
+
+            let has_line_break = line.ends_with('\n');
+            let line = line.trim_end();
+
+            if !line.is_empty() {
+                elf.add_item(&mut self.total, self.line_no, line)?;
+            } else if elf.items > 0 {
                 break;
+            } else if has_line_break {
+                // A blank line that doesn't end an elf, where normalising
+                // would drop one without a line break at the very end
+                return Err(ParseError::end_of_line("", "a calorie count")
+                    .on_line(self.line_no)
+                    .into());
             }
+        }
 
-            i += 1;
+        if elf.items == 0 {
+            return Ok(None);
         }
+
+        self.elves += 1;
+
+        Ok(Some(elf))
     }
+}
+
+impl<R: BufRead> Iterator for ElvesFromReader<R> {
+    type Item = Result<Elf, ReadError>;
 
-    top_three
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let next = self.next_elf().transpose();
+
+        if !matches!(next, Some(Ok(_))) {
+            self.done = true;
+        }
+
+        next
+    }
 }
 
-fn calories_per_elf<'a>(
-    input: &'a str,
-) -> CaloriesPerElf<impl Iterator<Item = Option<usize>> + 'a> {
-    CaloriesPerElf {
-        lines: input.lines().map(|l| {
-            if l.is_empty() {
-                None
-            } else {
-                Some(l.parse::<usize>().unwrap())
+impl<R: BufRead> FusedIterator for ElvesFromReader<R> {}
+
+/// The `n` biggest calorie counts like [`top_n`], reading the input from
+/// `reader` with [`elves_from_reader`]
+pub fn top_n_from_reader<R: BufRead>(reader: R, n: usize) -> Result<Vec<usize>, ReadError> {
+    top_n_and_total(elves_from_reader(reader), n).map(|(top, _)| top)
+}
+
+/// The `n` biggest calorie counts like [`top_n`], with `input` split at blank
+/// lines into a chunk for each of `jobs` threads. Fails with the same error
+/// as [`Day01::parse`] would.
+pub fn top_n_parallel(input: &str, n: usize, jobs: usize) -> Result<Vec<usize>, ParseError> {
+    let chunks = split_at_blank_lines(input, jobs);
+
+    let results = parallel::map_ordered(&chunks, jobs, |chunk| top_n_and_total(elves(chunk), n));
+
+    let mut tops = Vec::new();
+    let mut total = Some(0usize);
+
+    for result in results {
+        match result.expect("finding a chunk's top doesn't panic") {
+            Ok((top, chunk_total)) => {
+                tops.extend(top);
+                total = total.and_then(|t| t.checked_add(chunk_total));
             }
-        }),
+            Err(_) => total = None,
+        }
     }
-}
 
-struct CaloriesPerElf<I> {
-    lines: I,
+    // A chunk's errors count lines from the start of the chunk, and the total
+    // can only overflow once the chunks are added together, so the whole
+    // input is parsed again to find where the error is
+    if total.is_none() {
+        return Err(elves(input)
+            .find_map(Result::err)
+            .expect("a chunk with an error is part of an input with one"));
+    }
+
+    Ok(top_n(tops, n))
 }
 
-impl<I> Iterator for CaloriesPerElf<I>
-where
-    I: Iterator<Item = Option<usize>>,
-{
-    type Item = usize;
+/// Splits `input` into at most `chunks` pieces of about the same length, each
+/// but the last ending with a blank line so that no elf is split between two
+fn split_at_blank_lines(input: &str, chunks: usize) -> Vec<&str> {
+    let target = input.len().div_ceil(chunks.max(1)).max(1);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut cals = self.lines.next()?.unwrap();
+    let mut pieces = Vec::with_capacity(chunks);
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let end = rest
+            .as_bytes()
+            .get(target..)
+            .and_then(|after| after.windows(2).position(|w| w == b"\n\n"))
+            .map_or(rest.len(), |i| target + i + 2);
+
+        let (piece, next) = rest.split_at(end);
+
+        pieces.push(piece);
+        rest = next;
+    }
+
+    pieces
+}
+
+/// The `n` biggest of the `elves`' totals, and all their calories added up
+fn top_n_and_total<E>(
+    elves: impl Iterator<Item = Result<Elf, E>>,
+    n: usize,
+) -> Result<(Vec<usize>, usize), E> {
+    let mut total = 0;
+    let mut error = None;
 
-        while let Some(c) = self.lines.next().flatten() {
-            cals += c;
+    let totals = elves.map_while(|elf| match elf {
+        Ok(elf) => {
+            total += elf.total;
+            Some(elf.total)
         }
+        Err(e) => {
+            error = Some(e);
+            None
+        }
+    });
+
+    let top = top_n(totals, n);
 
-        Some(cals)
+    match error {
+        Some(e) => Err(e),
+        None => Ok((top, total)),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, input::normalise, solution::Part, utils::read_input};
 
     use super::*;
 
-    const SAMPLE: &str = "
-1000
-2000
-3000
+    use test_case::test_case;
 
-4000
+    const SAMPLE: &str = include_str!("../examples/day_01/one.txt");
 
-5000
-6000
+    #[test]
+    fn test_part_one() {
+        assert_eq!(
+            Day01::solution(&read_input(1))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(1, Part::One)
+        );
+    }
 
-7000
-8000
-9000
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            top_n(Day01::parse(SAMPLE.trim()).unwrap(), 3),
+            [24000, 11000, 10000]
+        );
 
-10000
-";
+        assert_eq!(
+            Day01::solution(&read_input(1))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(1, Part::Two)
+        );
+    }
 
     #[test]
-    fn test_part_one() {
-        assert_eq!(solution(SAMPLE.trim()).part_one, 24000);
+    fn test_elves() {
+        let elves: Vec<Elf> = elves(SAMPLE.trim()).map(Result::unwrap).collect();
 
-        assert_eq!(solution(&read_input(1)).part_one, 69836);
+        assert_eq!(elves.len(), 5);
+
+        assert_eq!(
+            elves[3],
+            Elf {
+                number: 4,
+                items: 3,
+                total: 24000,
+                largest: 9000,
+                smallest: 7000
+            }
+        );
+    }
+
+    #[test_case("1000\n2000\n\n3000\n")]
+    #[test_case("\u{feff}1000\r\n2000 \r\n\t\r\n3000")]
+    #[test_case("1000\n\n2000\n\n  ")]
+    #[test_case("1000\n  ")]
+    #[test_case("")]
+    #[test_case("\n")]
+    #[test_case("1000\n\n\n2000")]
+    #[test_case("1000\n\n2000\n\n\n")]
+    #[test_case("1000\n2x00")]
+    fn test_elves_from_reader(input: &str) {
+        let streamed: Result<Vec<Elf>, _> = elves_from_reader(input.as_bytes())
+            .map(|elf| {
+                elf.map_err(|e| match e {
+                    ReadError::Parse(e) => e,
+                    ReadError::Io(e) => panic!("{}", e),
+                })
+            })
+            .collect();
+
+        let expected: Result<Vec<Elf>, _> = elves(&normalise(input)).collect();
+
+        assert_eq!(streamed, expected);
     }
 
     #[test]
-    fn test_part_two() {
+    fn test_top_n_from_reader() {
         assert_eq!(
-            top_three_calorie_counts(SAMPLE.trim()),
+            top_n_from_reader(SAMPLE.as_bytes(), 3).unwrap(),
             [24000, 11000, 10000]
         );
 
-        assert_eq!(solution(&read_input(1)).part_two, 207968);
+        let e = top_n_from_reader(&b"1000\n\xff\n"[..], 3).unwrap_err();
+
+        assert!(matches!(e, ReadError::Io(_)));
+    }
+
+    #[test_case("", 4, &[])]
+    #[test_case("1\n2\n\n3\n\n4", 1, &["1\n2\n\n3\n\n4"])]
+    #[test_case("1\n2\n\n3\n\n4", 2, &["1\n2\n\n3\n\n", "4"])]
+    #[test_case("1\n2\n\n3\n\n4", 3, &["1\n2\n\n", "3\n\n4"])]
+    #[test_case("1\n2\n\n3\n\n4", 20, &["1\n2\n\n", "3\n\n", "4"])]
+    #[test_case("10\n20\n30\n40", 3, &["10\n20\n30\n40"])]
+    fn test_split_at_blank_lines(input: &str, chunks: usize, expected: &[&str]) {
+        assert_eq!(split_at_blank_lines(input, chunks), expected);
+    }
+
+    #[test_case("")]
+    #[test_case("1000\n\n\n2000\n\n3000")]
+    #[test_case("1000\n\n2000\n\n3x00\n\n4000")]
+    #[test_case("1000\n\n2000\n\n3000\n\n\n")]
+    #[test_case(&format!("{}\n\n1\n\n2\n\n3", usize::MAX))]
+    fn test_top_n_parallel(input: &str) {
+        let expected = Day01::parse(input).map(|calories| top_n(calories, 2));
+
+        for jobs in 1..=8 {
+            assert_eq!(top_n_parallel(input, 2, jobs), expected, "{} jobs", jobs);
+        }
+    }
+
+    #[test]
+    fn test_top_n_parallel_on_input() {
+        let input = read_input(1);
+        let calories = Day01::parse(&input).unwrap();
+
+        for jobs in [1, 2, 3, 8, 64] {
+            assert_eq!(
+                top_n_parallel(&input, 10, jobs).unwrap(),
+                top_n(calories.iter().copied(), 10)
+            );
+        }
+    }
+
+    #[test_case(0, &[])]
+    #[test_case(1, &[24000])]
+    #[test_case(5, &[24000, 11000, 10000, 6000, 4000])]
+    #[test_case(10, &[24000, 11000, 10000, 6000, 4000])]
+    #[test_case(100_000_000_000, &[24000, 11000, 10000, 6000, 4000])]
+    #[test_case(usize::MAX, &[24000, 11000, 10000, 6000, 4000])]
+    fn test_top_n(n: usize, expected: &[usize]) {
+        let calories = Day01::parse(SAMPLE.trim()).unwrap();
+
+        assert_eq!(top_n(calories, n), expected);
+    }
+
+    #[test]
+    fn test_top_n_keeps_ties() {
+        assert_eq!(top_n([5, 7, 5, 1, 7, 5], 4), [7, 7, 5, 5]);
+        assert_eq!(Day01::part_two(&vec![3, 4]), 7);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day01::parse("1000\n2000\n\n\n3000").unwrap_err();
+
+        assert_eq!((e.line, e.column), (4, 1));
+
+        let e = Day01::parse("1000\n20x0").unwrap_err();
+
+        assert_eq!((e.line, e.text.as_str()), (2, "20x0"));
+
+        let e = Day01::parse(&format!("{}\n\n1", usize::MAX)).unwrap_err();
+
+        assert_eq!((e.line, e.expected), (3, "fewer calories in total"));
     }
 }
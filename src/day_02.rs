@@ -1,209 +1,652 @@
-use std::str::FromStr;
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::read_to_string,
+    io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{
+    day::Day,
+    error::{parse_lines, ParseError},
+    solution::Solution,
+    utils::parse_as,
+};
+
+pub struct Day02;
+
+impl Day for Day02 {
+    const DAY: usize = 2;
+
+    /// Each line of the strategy guide, read both as a `Round` and a `RoundV2`
+    type Parsed<'a> = Vec<(Round, RoundV2)>;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Rules::rock_paper_scissors().parse_guide(input)
+    }
+
+    fn part_one(rounds: &Self::Parsed<'_>) -> Self::PartOne {
+        Rules::rock_paper_scissors().part_one(rounds)
+    }
 
-use crate::{solution::Solution, utils::parse_as};
+    fn part_two(rounds: &Self::Parsed<'_>) -> Self::PartTwo {
+        Rules::rock_paper_scissors().part_two(rounds)
+    }
+}
 
-pub fn main(input: &str) {
-    total_scores(input).print();
+/// The rules file for the game the puzzle plays
+const ROCK_PAPER_SCISSORS: &str = include_str!("../rules/rock_paper_scissors.txt");
+
+/// The rules of a game like rock, paper, scissors, with any odd number of
+/// shapes in a cycle. Each shape beats the half of the others that come
+/// before it, counting on from the end after the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    shapes: Vec<String>,
+    shape_scores: Vec<usize>,
+    /// The opponent's letter for each shape
+    opponent: Vec<String>,
+    /// Your letter for each shape in part one
+    you: Vec<String>,
+    /// The scores for a loss, a draw and a win
+    outcome_scores: [usize; 3],
+    /// The letters that tell you which shape to play in part two, each one
+    /// step further round the cycle from the opponent's shape than the one
+    /// before. The middle one draws.
+    strategy: Vec<String>,
 }
 
-fn total_scores(input: &str) -> Solution<usize, usize> {
-    let mut part_one = 0;
-    let mut part_two = 0;
+impl Rules {
+    /// The rules of the game in the puzzle
+    pub fn rock_paper_scissors() -> &'static Rules {
+        static RULES: OnceLock<Rules> = OnceLock::new();
 
-    for l in input.lines() {
-        part_one += parse_as::<Round>(l).score();
-        part_two += parse_as::<RoundV2>(l).as_round().score();
+        RULES.get_or_init(|| {
+            Rules::parse(ROCK_PAPER_SCISSORS).expect("the built-in rules are valid")
+        })
     }
 
-    Solution { part_one, part_two }
-}
+    /// Reads a rules file
+    pub fn load(path: &Path) -> Result<Self, RulesError> {
+        let contents = read_to_string(path).map_err(|e| RulesError::Io(path.to_owned(), e))?;
 
-#[derive(Debug, Clone, Copy)]
-struct Round {
-    opponent: RPS,
-    you: RPS,
-}
+        Self::parse(&contents).map_err(|e| RulesError::Parse(path.to_owned(), e))
+    }
 
-impl Round {
-    fn score(self) -> usize {
-        self.you.score() + self.you.result_against(self.opponent).score()
+    /// Parses lines of `<key>: <values>`, skipping blank lines and `#`
+    /// comments. The keys are `shapes`, `shape scores`, `opponent`, `you`,
+    /// `outcome scores` and `strategy`, each given once, and the values are
+    /// separated by spaces.
+    pub fn parse(contents: &str) -> Result<Self, ParseError> {
+        let mut builder = RulesBuilder::default();
+        let mut lines = 0;
+
+        for (i, line) in contents.lines().enumerate() {
+            lines = i + 1;
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            builder.line(line).map_err(|e| e.on_line(i + 1))?;
+        }
+
+        builder.finish().map_err(|e| e.on_line(lines + 1))
     }
-}
 
-impl FromStr for Round {
-    type Err = ();
+    pub fn shapes(&self) -> &[String] {
+        &self.shapes
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.chars();
+    /// Parses a strategy guide, reading each line both as a `Round` and a
+    /// `RoundV2`
+    pub fn parse_guide(&self, input: &str) -> Result<Vec<(Round, RoundV2)>, ParseError> {
+        parse_lines(input, |l| Ok((self.round(l)?, self.round_v2(l)?)))
+    }
 
-        let opponent = RPS::from_char(chars.next().unwrap());
+    /// Both parts' total scores for a strategy guide
+    pub fn play(&self, input: &str) -> Result<Solution<usize, usize>, ParseError> {
+        let rounds = self.parse_guide(input)?;
 
-        // Space char
-        chars.next().unwrap();
+        Ok(Solution {
+            part_one: self.part_one(&rounds),
+            part_two: self.part_two(&rounds),
+        })
+    }
 
-        let you = RPS::from_char(chars.next().unwrap());
+    fn part_one(&self, rounds: &[(Round, RoundV2)]) -> usize {
+        rounds.iter().map(|&(round, _)| self.score(round)).sum()
+    }
 
-        Ok(Round { opponent, you })
+    fn part_two(&self, rounds: &[(Round, RoundV2)]) -> usize {
+        rounds
+            .iter()
+            .map(|&(_, round)| self.score(self.as_round(round)))
+            .sum()
     }
-}
 
-#[derive(Debug, Clone, Copy)]
-struct RoundV2 {
-    opponent: RPS,
-    result: RPSResult,
-}
+    /// Parses a line like `A Y` as the opponent's shape and yours
+    pub fn round(&self, line: &str) -> Result<Round, ParseError> {
+        let (opponent, you) = split_round(line)?;
+
+        Ok(Round {
+            opponent: find(
+                &self.opponent,
+                line,
+                opponent,
+                "one of the opponent's letters",
+            )?,
+            you: find(&self.you, line, you, "one of your letters")?,
+        })
+    }
 
-impl RoundV2 {
-    fn as_round(self) -> Round {
-        let you = self.result.corresponding_choice_against(self.opponent);
+    /// Parses a line like `A Y` as the opponent's shape and a strategy letter
+    pub fn round_v2(&self, line: &str) -> Result<RoundV2, ParseError> {
+        let (opponent, strategy) = split_round(line)?;
+
+        Ok(RoundV2 {
+            opponent: find(
+                &self.opponent,
+                line,
+                opponent,
+                "one of the opponent's letters",
+            )?,
+            strategy: find(
+                &self.strategy,
+                line,
+                strategy,
+                "one of the strategy letters",
+            )?,
+        })
+    }
+
+    pub fn score(&self, round: Round) -> usize {
+        self.shape_scores[round.you] + self.outcome_scores[self.outcome(round) as usize]
+    }
+
+    pub fn outcome(&self, Round { opponent, you }: Round) -> Outcome {
+        let n = self.shapes.len();
+
+        // How far round the cycle your shape is from the opponent's
+        match (you + n - opponent) % n {
+            0 => Outcome::Draw,
+            steps if steps <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// The round played by following the strategy
+    pub fn as_round(&self, RoundV2 { opponent, strategy }: RoundV2) -> Round {
+        let n = self.shapes.len();
+        let middle = self.strategy.len() / 2;
 
         Round {
-            opponent: self.opponent,
-            you,
+            opponent,
+            you: (opponent + n + strategy - middle) % n,
         }
     }
 }
 
-impl FromStr for RoundV2 {
-    type Err = ();
+/// A rules file that's been read, each rule `None` until its line has been
+/// seen
+#[derive(Debug, Default)]
+struct RulesBuilder {
+    shapes: Option<Vec<String>>,
+    shape_scores: Option<Vec<usize>>,
+    opponent: Option<Vec<String>>,
+    you: Option<Vec<String>>,
+    outcome_scores: Option<[usize; 3]>,
+    strategy: Option<Vec<String>>,
+}
+
+impl RulesBuilder {
+    /// Reads one `<key>: <values>` line
+    fn line(&mut self, line: &str) -> Result<(), ParseError> {
+        let (key, values) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::new(line, line, "`<key>: <values>`"))?;
+
+        let values: Vec<&str> = values.split_whitespace().collect();
+
+        match key {
+            "shapes" => self.shapes(line, key, &values),
+            "shape scores" => self.shape_scores(line, key, &values),
+            "opponent" => self.opponent(line, key, &values),
+            "you" => self.you(line, key, &values),
+            "outcome scores" => self.outcome_scores(line, key, &values),
+            "strategy" => self.strategy(line, key, &values),
+            _ => Err(ParseError::new(
+                line,
+                key,
+                "`shapes`, `shape scores`, `opponent`, `you`, `outcome scores` or `strategy`",
+            )),
+        }
+    }
+
+    fn shapes(&mut self, line: &str, key: &str, values: &[&str]) -> Result<(), ParseError> {
+        not_yet_given(&self.shapes, line, key)?;
+
+        if values.len() < 3 || values.len().is_multiple_of(2) {
+            return Err(ParseError::new(
+                line,
+                values.last().copied().unwrap_or(key),
+                "an odd number of shapes, at least 3",
+            ));
+        }
+
+        self.shapes = Some(distinct(line, values, "a shape that isn't already listed")?);
+
+        Ok(())
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.chars();
+    fn shape_scores(&mut self, line: &str, key: &str, values: &[&str]) -> Result<(), ParseError> {
+        not_yet_given(&self.shape_scores, line, key)?;
 
-        let opponent = RPS::from_char(chars.next().unwrap());
+        let scores = values
+            .iter()
+            .map(|v| parse_as(line, v, "a score"))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        // Space char
-        chars.next().unwrap();
+        check_len(
+            line,
+            key,
+            values,
+            self.shape_count(line, key)?,
+            "a score for every shape",
+        )?;
 
-        let result = RPSResult::from_char(chars.next().unwrap());
+        self.shape_scores = Some(scores);
 
-        Ok(RoundV2 { opponent, result })
+        Ok(())
     }
-}
 
-#[derive(Debug, Clone, Copy)]
-enum RPS {
-    Rock,
-    Paper,
-    Scissors,
-}
+    fn opponent(&mut self, line: &str, key: &str, values: &[&str]) -> Result<(), ParseError> {
+        not_yet_given(&self.opponent, line, key)?;
 
-impl RPS {
-    fn score(self) -> usize {
-        match self {
-            RPS::Rock => 1,
-            RPS::Paper => 2,
-            RPS::Scissors => 3,
-        }
+        self.opponent = Some(self.letters(line, key, values)?);
+
+        Ok(())
     }
 
-    fn result_against(self, other: RPS) -> RPSResult {
-        match (self, other) {
-            (RPS::Rock, RPS::Scissors) | (RPS::Paper, RPS::Rock) | (RPS::Scissors, RPS::Paper) => {
-                RPSResult::Win
-            }
-            (RPS::Rock, RPS::Paper) | (RPS::Paper, RPS::Scissors) | (RPS::Scissors, RPS::Rock) => {
-                RPSResult::Loss
-            }
-            _ => RPSResult::Draw,
+    fn you(&mut self, line: &str, key: &str, values: &[&str]) -> Result<(), ParseError> {
+        not_yet_given(&self.you, line, key)?;
+
+        self.you = Some(self.letters(line, key, values)?);
+
+        Ok(())
+    }
+
+    fn outcome_scores(&mut self, line: &str, key: &str, values: &[&str]) -> Result<(), ParseError> {
+        not_yet_given(&self.outcome_scores, line, key)?;
+
+        check_len(line, key, values, 3, "scores for a loss, a draw and a win")?;
+
+        let mut scores = [0; 3];
+
+        for (score, v) in scores.iter_mut().zip(values) {
+            *score = parse_as(line, v, "a score")?;
         }
+
+        self.outcome_scores = Some(scores);
+
+        Ok(())
     }
 
-    fn from_char(c: char) -> Self {
-        match c {
-            'A' | 'X' => RPS::Rock,
-            'B' | 'Y' => RPS::Paper,
-            'C' | 'Z' => RPS::Scissors,
-            _ => panic!("Unknown char: {}", c),
+    fn strategy(&mut self, line: &str, key: &str, values: &[&str]) -> Result<(), ParseError> {
+        not_yet_given(&self.strategy, line, key)?;
+
+        let n = self.shape_count(line, key)?;
+
+        if values.is_empty() || values.len().is_multiple_of(2) || values.len() > n {
+            return Err(ParseError::new(
+                line,
+                values.last().copied().unwrap_or(key),
+                "an odd number of strategy letters, no more than there are shapes",
+            ));
         }
+
+        self.strategy = Some(distinct(line, values, "a letter that isn't already used")?);
+
+        Ok(())
+    }
+
+    /// A distinct letter for every shape
+    fn letters(&self, line: &str, key: &str, values: &[&str]) -> Result<Vec<String>, ParseError> {
+        check_len(
+            line,
+            key,
+            values,
+            self.shape_count(line, key)?,
+            "a letter for every shape",
+        )?;
+
+        distinct(line, values, "a letter that isn't already used")
+    }
+
+    /// How many shapes there are. Every other list has to come after the
+    /// shapes to know how long it should be.
+    fn shape_count(&self, line: &str, key: &str) -> Result<usize, ParseError> {
+        self.shapes
+            .as_ref()
+            .map(Vec::len)
+            .ok_or_else(|| ParseError::new(line, key, "the `shapes` line first"))
+    }
+
+    /// The rules, or an error at the end of the file for the first that's
+    /// missing
+    fn finish(self) -> Result<Rules, ParseError> {
+        let missing = |expected| ParseError::end_of_line("", expected);
+
+        Ok(Rules {
+            shapes: self.shapes.ok_or_else(|| missing("a `shapes` line"))?,
+            shape_scores: self
+                .shape_scores
+                .ok_or_else(|| missing("a `shape scores` line"))?,
+            opponent: self.opponent.ok_or_else(|| missing("an `opponent` line"))?,
+            you: self.you.ok_or_else(|| missing("a `you` line"))?,
+            outcome_scores: self
+                .outcome_scores
+                .ok_or_else(|| missing("an `outcome scores` line"))?,
+            strategy: self.strategy.ok_or_else(|| missing("a `strategy` line"))?,
+        })
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum RPSResult {
-    Win,
-    Draw,
-    Loss,
+/// Checks the rule for `key` hasn't already been given
+fn not_yet_given<T>(rule: &Option<T>, line: &str, key: &str) -> Result<(), ParseError> {
+    match rule {
+        Some(_) => Err(ParseError::new(line, key, "each key only once")),
+        None => Ok(()),
+    }
 }
 
-impl RPSResult {
-    fn score(self) -> usize {
-        match self {
-            RPSResult::Win => 6,
-            RPSResult::Draw => 3,
-            RPSResult::Loss => 0,
+/// Checks there are `len` `values`, pointing at the first extra one if there
+/// are too many or the end of the line if there are too few
+fn check_len(
+    line: &str,
+    key: &str,
+    values: &[&str],
+    len: usize,
+    expected: &'static str,
+) -> Result<(), ParseError> {
+    match values.get(len) {
+        Some(extra) => Err(ParseError::new(line, extra, expected)),
+        None if values.len() < len => Err(ParseError::end_of_line(line, expected)),
+        None if len == 0 => Err(ParseError::new(line, key, expected)),
+        None => Ok(()),
+    }
+}
+
+/// The `values` as owned strings, checking none of them is repeated
+fn distinct(
+    line: &str,
+    values: &[&str],
+    expected: &'static str,
+) -> Result<Vec<String>, ParseError> {
+    for (i, value) in values.iter().enumerate() {
+        if values[..i].contains(value) {
+            return Err(ParseError::new(line, value, expected));
         }
     }
 
-    fn from_char(c: char) -> Self {
-        match c {
-            'X' => RPSResult::Loss,
-            'Y' => RPSResult::Draw,
-            'Z' => RPSResult::Win,
-            _ => panic!("Unknown char: {}", c),
+    Ok(values.iter().map(|v| v.to_string()).collect())
+}
+
+/// The position of `token` in `letters`
+fn find(
+    letters: &[String],
+    line: &str,
+    token: &str,
+    expected: &'static str,
+) -> Result<usize, ParseError> {
+    letters
+        .iter()
+        .position(|l| l == token)
+        .ok_or_else(|| ParseError::new(line, token, expected))
+}
+
+fn split_round(s: &str) -> Result<(&str, &str), ParseError> {
+    s.split_once(' ')
+        .ok_or_else(|| ParseError::end_of_line(s, "a space"))
+}
+
+/// A round played with the shapes at these positions in the [`Rules`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    opponent: usize,
+    you: usize,
+}
+
+/// A round where you follow the strategy letter at this position in the
+/// [`Rules`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundV2 {
+    opponent: usize,
+    strategy: usize,
+}
+
+/// How a round ended for you, in the order of the [`Rules`]' outcome scores
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// A rules file that couldn't be loaded
+#[derive(Debug)]
+pub enum RulesError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, ParseError),
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesError::Io(path, e) => write!(f, "couldn't read `{}`: {}", path.display(), e),
+            RulesError::Parse(path, e) => write!(f, "invalid rules in `{}`: {}", path.display(), e),
         }
     }
+}
 
-    fn corresponding_choice_against(self, opponent: RPS) -> RPS {
+impl Error for RulesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            RPSResult::Draw => opponent,
-            RPSResult::Win => match opponent {
-                RPS::Rock => RPS::Paper,
-                RPS::Paper => RPS::Scissors,
-                RPS::Scissors => RPS::Rock,
-            },
-            RPSResult::Loss => match opponent {
-                RPS::Rock => RPS::Scissors,
-                RPS::Paper => RPS::Rock,
-                RPS::Scissors => RPS::Paper,
-            },
+            RulesError::Io(_, e) => Some(e),
+            RulesError::Parse(_, e) => Some(e),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
 
     use super::*;
 
     use test_case::test_case;
 
-    const SAMPLE: &str = "
-A Y
-B X
-C Z
-";
+    const RPSLS: &str = include_str!("../rules/rock_paper_scissors_lizard_spock.txt");
+    const RPS_7: &str = include_str!("../rules/rock_paper_scissors_7.txt");
 
     #[test_case("A Y", 8)]
     #[test_case("B X", 1)]
     #[test_case("C Z", 6)]
     fn test_round_scores(round_input: &str, score: usize) {
-        let round = parse_as::<Round>(round_input);
+        let rules = Rules::rock_paper_scissors();
 
-        assert_eq!(round.score(), score);
+        assert_eq!(rules.score(rules.round(round_input).unwrap()), score);
     }
 
     #[test]
-    fn part_one() {
-        assert_eq!(total_scores(SAMPLE.trim()).part_one, 15);
-
-        assert_eq!(total_scores(&read_input(2)).part_one, 12156);
+    fn test_part_one() {
+        assert_eq!(
+            Day02::solution(&read_input(2))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(2, Part::One)
+        );
     }
 
     #[test_case("A Y", 4)]
     #[test_case("B X", 1)]
     #[test_case("C Z", 7)]
     fn test_round_scores_part_two(round_input: &str, score: usize) {
-        let roundv2 = parse_as::<RoundV2>(round_input);
+        let rules = Rules::rock_paper_scissors();
+        let round = rules.as_round(rules.round_v2(round_input).unwrap());
+
+        assert_eq!(rules.score(round), score);
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            Day02::solution(&read_input(2))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(2, Part::Two)
+        );
+    }
+
+    #[test_case("A Q", 3, "Q")]
+    #[test_case("AY", 3, "")]
+    #[test_case("D X", 1, "D")]
+    #[test_case("X A", 1, "X")]
+    fn test_parse_error(line: &str, column: usize, text: &str) {
+        let e = Day02::parse(&format!("A Y\n{}", line)).unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, column, text));
+    }
+
+    #[test]
+    fn test_rock_paper_scissors_lizard_spock() {
+        let rules = Rules::parse(RPSLS).unwrap();
+
+        let shape = |name: &str| rules.shapes().iter().position(|s| s == name).unwrap();
+        let beats = |a, b| {
+            let round = Round {
+                opponent: shape(b),
+                you: shape(a),
+            };
+
+            rules.outcome(round) == Outcome::Win
+        };
+
+        let wins = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+
+        for (a, b) in wins {
+            assert!(beats(a, b), "{} beats {}", a, b);
+            assert!(!beats(b, a), "{} loses to {}", b, a);
+        }
 
-        assert_eq!(roundv2.as_round().score(), score);
+        // Lizard (4) loses to rock, Spock (2) beats it
+        assert_eq!(rules.play("A Y\nA W").unwrap().part_one, 4 + 2 + 6);
+        // Against lizard, W plays paper (3) to lose and Z rock (1) to win
+        assert_eq!(rules.play("D W\nD Z").unwrap().part_two, 3 + 1 + 6);
+
+        // With only three strategy letters, you lose or win by one step
+        let rules = Rules::parse(&RPSLS.replace("strategy: V W X Y Z", "strategy: L D W")).unwrap();
+        let round = |line| rules.as_round(rules.round_v2(line).unwrap());
+
+        assert_eq!(round("D L").you, shape("Paper"));
+        assert_eq!(round("D D").you, shape("Lizard"));
+        assert_eq!(round("D W").you, shape("Scissors"));
     }
 
     #[test]
-    fn part_two() {
-        assert_eq!(total_scores(SAMPLE.trim()).part_two, 12);
+    fn test_every_shape_beats_half_the_others() {
+        for contents in [ROCK_PAPER_SCISSORS, RPSLS, RPS_7] {
+            let rules = Rules::parse(contents).unwrap();
+            let n = rules.shapes().len();
+
+            for you in 0..n {
+                let outcomes: Vec<Outcome> = (0..n)
+                    .map(|opponent| rules.outcome(Round { opponent, you }))
+                    .collect();
+
+                let count = |o| outcomes.iter().filter(|&&x| x == o).count();
+
+                assert_eq!(
+                    (
+                        count(Outcome::Win),
+                        count(Outcome::Draw),
+                        count(Outcome::Loss)
+                    ),
+                    (n / 2, 1, n / 2)
+                );
+            }
+
+            // The strategy letters go from losing, through drawing, to winning
+            for opponent in 0..n {
+                let outcomes: Vec<Outcome> = (0..rules.strategy.len())
+                    .map(|strategy| rules.outcome(rules.as_round(RoundV2 { opponent, strategy })))
+                    .collect();
+
+                let middle = outcomes.len() / 2;
+
+                assert!(outcomes[..middle].iter().all(|&o| o == Outcome::Loss));
+                assert_eq!(outcomes[middle], Outcome::Draw);
+                assert!(outcomes[middle + 1..].iter().all(|&o| o == Outcome::Win));
+            }
+        }
+    }
+
+    #[test_case("shapes: Rock Paper", 1, 14, "Paper")]
+    #[test_case("shapes: Rock Paper Rock", 1, 20, "Rock")]
+    #[test_case("shape scores: 1 2 3", 1, 1, "shape scores")]
+    #[test_case("shapes: A B C\nshape scores: 1 2", 2, 18, "")]
+    #[test_case("shapes: A B C\nshape scores: 1 2 3 4", 2, 21, "4")]
+    #[test_case("shapes: A B C\nshape scores: 1 two 3", 2, 17, "two")]
+    #[test_case("shapes: A B C\nyou: X Y X", 2, 10, "X")]
+    #[test_case("shapes: A B C\nstrategy: X Y", 2, 13, "Y")]
+    #[test_case("shapes: A B C\nstrategy: V W X Y Z", 2, 19, "Z")]
+    #[test_case("shapes: A B C\nshapes: A B C", 2, 1, "shapes")]
+    #[test_case("outcome scores: 0 3", 1, 20, "")]
+    #[test_case("colours: red", 1, 1, "colours")]
+    #[test_case("shapes A B C", 1, 1, "shapes A B C")]
+    #[test_case("# Nothing but a comment", 2, 1, "")]
+    fn test_rules_parse_error(contents: &str, line: usize, column: usize, text: &str) {
+        let e = Rules::parse(contents).unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (line, column, text));
+    }
+
+    #[test]
+    fn test_load() {
+        let path = Path::new("rules/rock_paper_scissors.txt");
+
+        assert_eq!(&Rules::load(path).unwrap(), Rules::rock_paper_scissors());
+
+        let path = std::env::temp_dir().join(format!("aoc-2022-rules-{}.txt", std::process::id()));
+        std::fs::write(&path, "# Too few\nshapes: Rock Paper\n").unwrap();
+
+        match Rules::load(&path) {
+            Err(RulesError::Parse(p, e)) => {
+                assert_eq!(p, path);
+                assert_eq!((e.line, e.column), (2, 14));
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        std::fs::remove_file(&path).unwrap();
 
-        assert_eq!(total_scores(&read_input(2)).part_two, 10835);
+        assert!(matches!(Rules::load(&path), Err(RulesError::Io(..))));
     }
 }
@@ -1,38 +1,83 @@
-use crate::{groups_of::groups_of, solution::Solution};
+use crate::{
+    day::Day,
+    error::{parse_lines, ParseError},
+    groups_of::groups_of,
+};
 
-pub fn main(input: &str) {
-    solution(input).print();
-}
+pub struct Day03;
+
+impl Day for Day03 {
+    const DAY: usize = 3;
+
+    type Parsed<'a> = Vec<&'a [u8]>;
 
-fn solution(input: &str) -> Solution<usize, usize> {
-    let mut sum_of_duplicates = 0;
-    let mut sum_of_badges = 0;
+    type PartOne = usize;
+    type PartTwo = usize;
 
-    for group in groups_of(input.lines().map(|l| l.as_bytes())) {
-        sum_of_badges += groups_badge(group);
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let rucksacks = parse_lines(input, parse_rucksack)?;
 
-        sum_of_duplicates += group.into_iter().map(duplicate_item).sum::<usize>();
+        let mut groups = groups_of::<3, _>(input.lines().enumerate());
+
+        for [(_, a), (_, b), (i, c)] in &mut groups {
+            if groups_badge([a, b, c].map(str::as_bytes)).is_none() {
+                return Err(ParseError::new(c, c, "an item the whole group carries").on_line(i + 1));
+            }
+        }
+
+        if !groups.into_remainder().is_empty() {
+            return Err(
+                ParseError::end_of_line("", "a full group of three rucksacks")
+                    .on_line(rucksacks.len() + 1),
+            );
+        }
+
+        Ok(rucksacks)
     }
 
-    Solution {
-        part_one: sum_of_duplicates,
-        part_two: sum_of_badges,
+    fn part_one(rucksacks: &Self::Parsed<'_>) -> Self::PartOne {
+        rucksacks
+            .iter()
+            .map(|r| duplicate_item(r).expect("parsing checked every rucksack"))
+            .sum()
+    }
+
+    fn part_two(rucksacks: &Self::Parsed<'_>) -> Self::PartTwo {
+        groups_of(rucksacks.iter().copied())
+            .map(|g| groups_badge(g).expect("parsing checked every group"))
+            .sum()
     }
 }
 
-fn groups_badge(group: [&[u8]; 3]) -> usize {
-    let in_a = item_priorities_in(group[0]);
-    let in_b = item_priorities_in(group[1]);
+fn parse_rucksack(line: &str) -> Result<&[u8], ParseError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        let item = &line[i..i + c.len_utf8()];
+
+        return Err(ParseError::new(line, item, "an item from a-z or A-Z"));
+    }
 
-    for &x in group[2] {
-        let p = as_priority(x);
+    let rucksack = line.as_bytes();
 
-        if in_a[p - 1] && in_b[p - 1] {
-            return p;
-        }
+    if rucksack.len() % 2 == 1 {
+        return Err(ParseError::end_of_line(line, "an even number of items"));
+    }
+
+    if duplicate_item(rucksack).is_none() {
+        return Err(ParseError::new(line, line, "an item in both compartments"));
     }
 
-    panic!("Badge not found");
+    Ok(rucksack)
+}
+
+/// The priority of the item all three rucksacks in `group` have
+fn groups_badge(group: [&[u8]; 3]) -> Option<usize> {
+    let in_a = item_priorities_in(group[0]);
+    let in_b = item_priorities_in(group[1]);
+
+    group[2]
+        .iter()
+        .map(|&x| as_priority(x))
+        .find(|&p| in_a[p - 1] && in_b[p - 1])
 }
 
 fn item_priorities_in(items: &[u8]) -> [bool; 52] {
@@ -45,20 +90,15 @@ fn item_priorities_in(items: &[u8]) -> [bool; 52] {
     already_had
 }
 
-fn duplicate_item(rucksack: &[u8]) -> usize {
+/// The priority of the item in both halves of `rucksack`
+fn duplicate_item(rucksack: &[u8]) -> Option<usize> {
     let (a, b) = rucksack.split_at(rucksack.len() / 2);
 
     let already_had = item_priorities_in(a);
 
-    for &x in b {
-        let p = as_priority(x);
-
-        if already_had[p - 1] {
-            return p;
-        }
-    }
-
-    panic!("No duplicate found");
+    b.iter()
+        .map(|&x| as_priority(x))
+        .find(|&p| already_had[p - 1])
 }
 
 fn as_priority(c: u8) -> usize {
@@ -71,30 +111,48 @@ fn as_priority(c: u8) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
 
     use super::*;
 
-    const SAMPLE: &str = "
-vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-";
+    use test_case::test_case;
 
     #[test]
     fn test_part_one() {
-        assert_eq!(solution(SAMPLE.trim()).part_one, 157);
-
-        assert_eq!(solution(&read_input(3)).part_one, 7980);
+        assert_eq!(
+            Day03::solution(&read_input(3))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(3, Part::One)
+        );
     }
 
     #[test]
     fn test_part_two() {
-        assert_eq!(solution(SAMPLE.trim()).part_two, 70);
+        assert_eq!(
+            Day03::solution(&read_input(3))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(3, Part::Two)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day03::parse("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRj-qzjGDL").unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 9, "-"));
+    }
+
+    #[test_case("abc", 1, 4, "an even number of items")]
+    #[test_case("abcd", 1, 1, "an item in both compartments")]
+    #[test_case("abca\nbdbe\ncfcg", 3, 1, "an item the whole group carries")]
+    #[test_case("abca\n", 2, 1, "a full group of three rucksacks")]
+    fn test_invalid_rucksacks(input: &str, line: usize, column: usize, expected: &str) {
+        let e = Day03::parse(input).unwrap_err();
 
-        assert_eq!(solution(&read_input(3)).part_two, 2881);
+        assert_eq!((e.line, e.column, e.expected), (line, column, expected));
     }
 }
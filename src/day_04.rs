@@ -1,34 +1,39 @@
 use std::{ops::RangeInclusive, str::FromStr};
 
-use crate::{solution::Solution, utils::parse_as};
+use crate::{
+    day::Day,
+    error::{parse_lines, ParseError},
+    utils::parse_as,
+};
 
-pub fn main(input: &str) {
-    overlapping_assignments(input).print();
-}
+pub struct Day04;
+
+impl Day for Day04 {
+    const DAY: usize = 4;
 
-fn overlapping_assignments(input: &str) -> Solution<usize, usize> {
-    let mut fully_contained_count = 0;
-    let mut any_overlap_count = 0;
+    type Parsed<'a> = Vec<AssignmentPair>;
 
-    for l in input.lines() {
-        let pair = parse_as::<AssignmentPair>(l);
+    type PartOne = usize;
+    type PartTwo = usize;
 
-        if pair.one_fully_contains_the_other() {
-            fully_contained_count += 1;
-        }
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_lines(input, str::parse)
+    }
 
-        if pair.has_any_overlap() {
-            any_overlap_count += 1;
-        }
+    fn part_one(pairs: &Self::Parsed<'_>) -> Self::PartOne {
+        pairs
+            .iter()
+            .filter(|p| p.one_fully_contains_the_other())
+            .count()
     }
 
-    Solution {
-        part_one: fully_contained_count,
-        part_two: any_overlap_count,
+    fn part_two(pairs: &Self::Parsed<'_>) -> Self::PartTwo {
+        pairs.iter().filter(|p| p.has_any_overlap()).count()
     }
 }
 
-struct AssignmentPair {
+#[derive(Debug)]
+pub struct AssignmentPair {
     a: RangeInclusive<usize>,
     b: RangeInclusive<usize>,
 }
@@ -49,50 +54,64 @@ impl AssignmentPair {
 }
 
 impl FromStr for AssignmentPair {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (a, b) = s.split_once(',').unwrap();
+        let (a, b) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(s, s, "two ranges separated by a comma"))?;
 
         Ok(AssignmentPair {
-            a: parse_range(a),
-            b: parse_range(b),
+            a: parse_range(s, a)?,
+            b: parse_range(s, b)?,
         })
     }
 }
 
-fn parse_range(s: &str) -> RangeInclusive<usize> {
-    let (start, end) = s.split_once('-').unwrap();
+fn parse_range(line: &str, s: &str) -> Result<RangeInclusive<usize>, ParseError> {
+    let (start, end) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(line, s, "a range like 2-4"))?;
 
-    RangeInclusive::new(parse_as(start), parse_as(end))
+    Ok(RangeInclusive::new(
+        parse_as(line, start, "a section number")?,
+        parse_as(line, end, "a section number")?,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
 
     use super::*;
 
-    const SAMPLE: &str = "
-2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8
-";
-
     #[test]
-    fn part_one() {
-        assert_eq!(overlapping_assignments(SAMPLE.trim()).part_one, 2);
+    fn test_part_one() {
+        assert_eq!(
+            Day04::solution(&read_input(4))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(4, Part::One)
+        );
+    }
 
-        assert_eq!(overlapping_assignments(&read_input(4)).part_one, 651);
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            Day04::solution(&read_input(4))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(4, Part::Two)
+        );
     }
 
     #[test]
-    fn part_two() {
-        assert_eq!(overlapping_assignments(SAMPLE.trim()).part_two, 4);
+    fn test_parse_error() {
+        let e = Day04::parse("2-4,6-8\n2-3,4-x5").unwrap_err();
 
-        assert_eq!(overlapping_assignments(&read_input(4)).part_two, 956);
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 7, "x5"));
+        assert_eq!(e.expected, "a section number");
     }
 }
@@ -1,118 +1,277 @@
 use std::{
     fmt::{Display, Write},
-    num::ParseIntError,
-    str::{Chars, FromStr},
+    str::FromStr,
 };
 
-use crate::{solution::Solution, utils::parse_as};
+use crate::{
+    day::Day,
+    error::ParseError,
+    groups_of::{split_on_blank_lines, Block},
+    utils::parse_as,
+};
 
-pub fn main(input: &str) {
-    solution(input).print();
-}
+pub struct Day05;
+
+impl Day for Day05 {
+    const DAY: usize = 5;
+
+    type Parsed<'a> = (Stacks, Vec<Instruction>);
+
+    type PartOne = Stacks;
+    type PartTwo = Stacks;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut blocks = split_on_blank_lines(input);
+
+        let diagram = blocks.next().unwrap_or(Block { line: 1, text: "" });
+        let mut lines = diagram.lines();
+
+        let stacks = parse_stacks(&mut lines)?;
+
+        if let Some((i, l)) = lines.next() {
+            return Err(ParseError::new(l, l, "a blank line").on_line(i));
+        }
 
-fn solution(input: &str) -> Solution<Stacks, Stacks> {
-    let mut lines = input.lines();
+        // How many crates each stack will have, to check every move can be
+        // made
+        let mut heights = stacks.0.map(|s| s.len);
 
-    let mut stacks_a = parse_stacks(&mut lines);
-    let mut stacks_b = stacks_a;
+        let instructions = blocks
+            .next()
+            .into_iter()
+            .flat_map(|b| b.lines())
+            .map(|(i, l)| {
+                let instruction: Instruction = l.parse().map_err(|e: ParseError| e.on_line(i))?;
 
-    // Skip blank line
-    lines.next().unwrap();
+                instruction
+                    .apply_to_heights(l, &mut heights)
+                    .map_err(|e| e.on_line(i))?;
 
-    for instruction in lines.map(parse_as) {
-        stacks_a.update_v1(instruction);
+                Ok(instruction)
+            })
+            .collect::<Result<_, _>>()?;
 
-        stacks_b.update_v2(instruction);
+        // A blank line among the instructions
+        if let Some(block) = blocks.next() {
+            return Err(ParseError::end_of_line("", "an instruction").on_line(block.line - 1));
+        }
+
+        Ok((stacks, instructions))
+    }
+
+    fn part_one((stacks, instructions): &Self::Parsed<'_>) -> Self::PartOne {
+        let mut stacks = *stacks;
+
+        for &instruction in instructions {
+            stacks.update_v1(instruction);
+        }
+
+        stacks
     }
 
-    Solution {
-        part_one: stacks_a,
-        part_two: stacks_b,
+    fn part_two((stacks, instructions): &Self::Parsed<'_>) -> Self::PartTwo {
+        let mut stacks = *stacks;
+
+        for &instruction in instructions {
+            stacks.update_v2(instruction);
+        }
+
+        stacks
     }
 }
 
-fn parse_stacks<'a, I>(lines: &mut I) -> Stacks
+/// Parses the stack diagram from `lines` of `(line number, line)`, up to and
+/// including the line of stack numbers
+fn parse_stacks<'a, I>(lines: &mut I) -> Result<Stacks, ParseError>
 where
-    I: Iterator<Item = &'a str>,
+    I: Iterator<Item = (usize, &'a str)>,
 {
     let mut stacks = Stacks::default();
+    let mut line_no = 0;
 
-    for line in lines {
-        if line.starts_with(" 1 ") {
-            return stacks;
+    for (i, line) in lines {
+        line_no = i;
+
+        // The number line's trailing space may have been trimmed, so with one
+        // stack it's just ` 1`
+        if line.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+            return Ok(stacks);
         }
 
-        for (i, item) in StackLineParser::new(line).enumerate() {
-            if let Some(c) = item {
-                stacks.0[i].add_to_bottom(c);
-            }
+        for (n, item) in StackLineParser::new(line).enumerate() {
+            let add_crate = |item: Option<&str>| {
+                let Some(c) = item else {
+                    return Ok(());
+                };
+
+                let stack = stacks
+                    .0
+                    .get_mut(n)
+                    .ok_or_else(|| ParseError::new(line, c, "at most 9 stacks"))?;
+
+                if stack.len == STACK_MAX_SIZE {
+                    return Err(ParseError::new(line, c, "at most 40 crates per stack"));
+                }
+
+                stack.add_to_bottom(c.chars().next().unwrap());
+
+                Ok(())
+            };
+
+            item.and_then(add_crate).map_err(|e| e.on_line(i))?;
         }
     }
 
-    panic!("Did not reach end of stacks")
+    Err(ParseError::end_of_line("", "a line of stack numbers").on_line(line_no + 1))
 }
 
+/// An instruction to move `num` crates from one stack to another
 #[derive(Debug, Clone, Copy)]
-struct Instruction {
+pub struct Instruction {
     num: usize,
     from: usize,
     to: usize,
 }
 
 impl FromStr for Instruction {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut words = s.strip_prefix("move ").unwrap().split(' ');
+        let mut words = s.split(' ');
+
+        let mut next_word = |expected| {
+            words
+                .next()
+                .ok_or_else(|| ParseError::end_of_line(s, expected))
+        };
+
+        // `expected` is the keyword wrapped in backticks
+        let keyword = |word: &str, expected: &'static str| {
+            if word == expected.trim_matches('`') {
+                Ok(())
+            } else {
+                Err(ParseError::new(s, word, expected))
+            }
+        };
 
-        let num = words.next().unwrap().parse()?;
+        keyword(next_word("`move`")?, "`move`")?;
 
-        // from
-        words.next().unwrap();
+        let num = parse_as(s, next_word("a number of crates")?, "a number of crates")?;
 
-        let from = words.next().unwrap().parse()?;
+        keyword(next_word("`from`")?, "`from`")?;
 
-        // to
-        words.next().unwrap();
+        let from = parse_stack_number(s, next_word("a stack number")?)?;
 
-        let to = words.next().unwrap().parse()?;
+        keyword(next_word("`to`")?, "`to`")?;
+
+        let to = parse_stack_number(s, next_word("a stack number")?)?;
+
+        if let Some(extra) = words.next() {
+            return Err(ParseError::new(s, extra, "end of line"));
+        }
 
         Ok(Self { num, from, to })
     }
 }
 
-struct StackLineParser<'a> {
-    chars: Chars<'a>,
+impl Instruction {
+    /// Moves the crates between stacks with the given `heights`, failing if
+    /// there aren't enough crates or the stack they're moved to would overflow
+    fn apply_to_heights(self, line: &str, heights: &mut [usize; 9]) -> Result<(), ParseError> {
+        let word = |n| line.split(' ').nth(n).unwrap_or_default();
+
+        if self.num > heights[self.from - 1] {
+            return Err(ParseError::new(
+                line,
+                word(1),
+                "no more crates than the stack has",
+            ));
+        }
+
+        heights[self.from - 1] -= self.num;
+
+        if heights[self.to - 1] + self.num > STACK_MAX_SIZE {
+            return Err(ParseError::new(
+                line,
+                word(5),
+                "a stack with room for the crates",
+            ));
+        }
+
+        heights[self.to - 1] += self.num;
+
+        Ok(())
+    }
+}
+
+fn parse_stack_number(line: &str, word: &str) -> Result<usize, ParseError> {
+    match parse_as(line, word, "a stack number from 1 to 9")? {
+        n @ 1..=9 => Ok(n),
+        _ => Err(ParseError::new(line, word, "a stack number from 1 to 9")),
+    }
+}
+
+/// Reads the crates in one line of the stack diagram, yielding the crate's
+/// letter for each stack or `None` where the stack is empty
+pub struct StackLineParser<'a> {
+    line: &'a str,
+    pos: usize,
 }
 
 impl<'a> StackLineParser<'a> {
-    pub fn new(l: &'a str) -> Self {
-        Self { chars: l.chars() }
+    pub fn new(line: &'a str) -> Self {
+        Self { line, pos: 0 }
     }
 }
 
 impl<'a> Iterator for StackLineParser<'a> {
-    type Item = Option<char>;
+    type Item = Result<Option<&'a str>, ParseError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.chars.next()?; // [ char
+        let rest = &self.line[self.pos..];
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        let cell = rest.get(..3).unwrap_or(rest);
+
+        let item = match cell.as_bytes() {
+            b"   " => None,
+            [b'[', c, b']'] if c.is_ascii_alphabetic() => Some(&cell[1..2]),
+            _ => {
+                self.pos = self.line.len();
+
+                return Some(Err(ParseError::new(self.line, cell, "a crate like [A]")));
+            }
+        };
+
+        self.pos += cell.len();
 
-        let c = self.chars.next()?;
+        // Space between stacks
+        if let Some(next) = self.line[self.pos..].chars().next() {
+            if next != ' ' {
+                let separator = &self.line[self.pos..self.pos + next.len_utf8()];
 
-        let _ = self.chars.next(); // ] char
-        let _ = self.chars.next(); // possible space separator
+                self.pos = self.line.len();
 
-        let item = if c == ' ' { None } else { Some(c) };
+                return Some(Err(ParseError::new(self.line, separator, "a space")));
+            }
+
+            self.pos += 1;
+        }
 
-        Some(item)
+        Some(Ok(item))
     }
 }
 
+/// The nine stacks of crates, numbered from 1 in instructions
 #[derive(Debug, Clone, Copy, Default)]
-struct Stacks(pub [Stack; 9]);
+pub struct Stacks(pub [Stack; 9]);
 
 impl Stacks {
-    fn update_v1(&mut self, Instruction { num, from, to }: Instruction) {
+    /// Moves crates one at a time, as the CrateMover 9000 does
+    pub fn update_v1(&mut self, Instruction { num, from, to }: Instruction) {
         for _ in 0..num {
             let c = self.0[from - 1].pop();
 
@@ -120,29 +279,29 @@ impl Stacks {
         }
     }
 
-    fn update_v2(&mut self, Instruction { num, from, to }: Instruction) {
-        let from_stack = &self.0[from - 1];
+    /// Moves all the crates at once, keeping their order, as the CrateMover
+    /// 9001 does
+    pub fn update_v2(&mut self, Instruction { num, from, to }: Instruction) {
+        // Taking the crates off first means moving them to the same stack
+        // puts them back where they were
+        let from_stack = &mut self.0[from - 1];
+        from_stack.len -= num;
         let from_len = from_stack.len;
 
         let mut items = [' '; STACK_MAX_SIZE];
-        (&mut items[..num]).copy_from_slice(&from_stack.items[from_len - num..from_len]);
+        items[..num].copy_from_slice(&from_stack.items[from_len..from_len + num]);
 
         let to_stack = &mut self.0[to - 1];
         let to_len = to_stack.len;
-        (&mut to_stack.items[to_len..to_len + num]).copy_from_slice(&items[..num]);
+        to_stack.items[to_len..to_len + num].copy_from_slice(&items[..num]);
 
         to_stack.len += num;
-        self.0[from - 1].len -= num
     }
 }
 
 impl Display for Stacks {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for stack in self.0 {
-            if stack.len == 0 {
-                break;
-            }
-
+        for stack in self.0.iter().filter(|s| s.len > 0) {
             f.write_char(stack.items[stack.len - 1])?;
         }
 
@@ -153,7 +312,7 @@ impl Display for Stacks {
 const STACK_MAX_SIZE: usize = 40;
 
 #[derive(Debug, Clone, Copy)]
-struct Stack {
+pub struct Stack {
     len: usize,
     items: [char; STACK_MAX_SIZE],
 }
@@ -168,6 +327,11 @@ impl Default for Stack {
 }
 
 impl Stack {
+    /// The crates in the stack, from the bottom up
+    pub fn crates(&self) -> &[char] {
+        &self.items[..self.len]
+    }
+
     pub fn pop(&mut self) -> char {
         let c = self.items[self.len - 1];
         self.len -= 1;
@@ -195,45 +359,82 @@ impl Stack {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, input::normalise, solution::Part, utils::read_input};
 
     use super::*;
 
-    const SAMPLE: &str = "
-    [D]
-[N] [C]
-[Z] [M] [P]
- 1   2   3
+    use test_case::test_case;
 
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
-";
+    const SAMPLE: &str = include_str!("../examples/day_05/one.txt");
 
     #[test]
-    fn part_one() {
+    fn test_part_one() {
         assert_eq!(
-            format!("{}", solution(SAMPLE.trim_matches('\n')).part_one),
-            "CMZ"
+            Day05::solution(&read_input(5))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(5, Part::One)
         );
+    }
 
+    #[test]
+    fn test_part_two() {
         assert_eq!(
-            format!("{}", solution(&read_input(5)).part_one),
-            "GFTNRBZPF"
+            Day05::solution(&read_input(5))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(5, Part::Two)
         );
     }
 
+    #[test_case("[Z] [M] {P}", 3, 9, "{P}")]
+    #[test_case("[Z] [M]-[P]", 3, 8, "-")]
+    #[test_case("move 1 from 2 to 10", 7, 18, "10")]
+    #[test_case("move 1 from 2 into 1", 7, 15, "into")]
+    #[test_case("move 1 from 2", 7, 14, "")]
+    #[test_case("move 4 from 2 to 1", 6, 6, "4")]
+    #[test_case(" 1 ", 5, 1, " 1 ")]
+    #[test_case("", 8, 1, "")]
+    fn test_parse_error(replace: &str, line: usize, column: usize, text: &str) {
+        let lines = SAMPLE.trim_matches('\n').lines().enumerate();
+        let input = lines
+            .map(|(i, l)| if i + 1 == line { replace } else { l })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let e = Day05::parse(&input).unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (line, column, text));
+    }
+
     #[test]
-    fn part_two() {
-        assert_eq!(
-            format!("{}", solution(SAMPLE.trim_matches('\n')).part_two),
-            "MCD"
-        );
+    fn test_full_stacks() {
+        let diagram = format!("{} 1   2 \n\n", "[A] [B]\n".repeat(40));
 
-        assert_eq!(
-            format!("{}", solution(&read_input(5)).part_two),
-            "VRQWPDSGP"
-        );
+        let e = Day05::parse(&format!("{}move 1 from 2 to 1", diagram)).unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (43, 18, "1"));
+
+        let stacks = Day05::parse(&format!("{}move 40 from 1 to 1", diagram)).unwrap();
+
+        assert_eq!(Day05::part_two(&stacks).to_string(), "AB");
+    }
+
+    #[test]
+    fn test_empty_stacks_are_skipped() {
+        let stacks = Day05::parse("[A]     [C]\n 1   2   3 \n\nmove 1 from 1 to 2").unwrap();
+
+        assert_eq!(Day05::part_one(&stacks).to_string(), "AC");
+    }
+
+    #[test]
+    fn test_one_stack() {
+        let input = normalise("[A] \n[B] \n 1 \n\nmove 1 from 1 to 1\n");
+
+        let stacks = Day05::parse(&input).unwrap();
+
+        assert_eq!(Day05::part_one(&stacks).to_string(), "A");
     }
 }
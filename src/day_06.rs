@@ -1,41 +1,60 @@
-use crate::solution::Solution;
+use crate::{day::Day, error::ParseError};
 
-pub fn main(input: &str) {
-    solution(input).print();
-}
+pub struct Day06;
 
-fn solution(input: &str) -> Solution<usize, usize> {
-    let data = input.as_bytes();
+impl Day for Day06 {
+    const DAY: usize = 6;
 
-    let mut i = 0;
+    type Parsed<'a> = &'a [u8];
 
-    loop {
-        let min_step = min_next_marker_step(&data[i..i + 4]);
+    type PartOne = usize;
+    type PartTwo = usize;
 
-        i += min_step;
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let data = input.trim_end();
 
-        if min_step == 0 {
-            break;
+        match data.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            Some((i, c)) => Err(ParseError::new(
+                data,
+                &data[i..i + c.len_utf8()],
+                "a letter from a-z",
+            )),
+            // A start-of-message marker contains a start-of-packet marker, so
+            // this checks for both
+            None if marker_start(data.as_bytes(), 0, 14).is_none() => {
+                Err(ParseError::end_of_line(data, "a start-of-message marker"))
+            }
+            None => Ok(data.as_bytes()),
         }
     }
 
-    let part_one = i + 4;
+    fn part_one(data: &Self::Parsed<'_>) -> Self::PartOne {
+        marker_start(data, 0, 4).expect(HAS_MARKERS) + 4
+    }
+
+    fn part_two(data: &Self::Parsed<'_>) -> Self::PartTwo {
+        // Only once we’ve found 4 distinct characters in a row can we find 14
+        // distinct chars in a row
+        let i = marker_start(data, 0, 4).expect(HAS_MARKERS);
 
-    // Only once we’ve found 4 distinct characters in a row can we find 14
-    // distinct chars in a row
+        marker_start(data, i, 14).expect(HAS_MARKERS) + 14
+    }
+}
+
+const HAS_MARKERS: &str = "parsing checked there are markers";
+
+/// Where the first run of `marker_len` distinct chars from `i` onwards starts,
+/// if there is one
+fn marker_start(data: &[u8], mut i: usize, marker_len: usize) -> Option<usize> {
     loop {
-        let min_step = min_next_marker_step(&data[i..i + 14]);
+        let min_step = min_next_marker_step(data.get(i..i + marker_len)?);
 
         i += min_step;
 
         if min_step == 0 {
-            break;
+            return Some(i);
         }
     }
-
-    let part_two = i + 14;
-
-    Solution { part_one, part_two }
 }
 
 fn min_next_marker_step(group: &[u8]) -> usize {
@@ -60,35 +79,40 @@ fn min_next_marker_step(group: &[u8]) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
 
     use super::*;
-    use test_case::test_case;
-
-    #[test_case("bvwbjplbgvbhsrlpgdmjqwftvncz", 5)]
-    #[test_case("nppdvjthqldpwncqszvftbrmjlhg", 6)]
-    #[test_case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10)]
-    #[test_case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11)]
-    fn part_one(input: &str, expected: usize) {
-        assert_eq!(solution(input).part_one, expected);
-    }
 
     #[test]
-    fn part_one_result() {
-        assert_eq!(solution(&read_input(6)).part_one, 1198);
+    fn test_part_one() {
+        assert_eq!(
+            Day06::solution(&read_input(6))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(6, Part::One)
+        );
     }
 
-    #[test_case("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19)]
-    #[test_case("bvwbjplbgvbhsrlpgdmjqwftvncz", 23)]
-    #[test_case("nppdvjthqldpwncqszvftbrmjlhg", 23)]
-    #[test_case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29)]
-    #[test_case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26)]
-    fn part_two(input: &str, expected: usize) {
-        assert_eq!(solution(input).part_two, expected);
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            Day06::solution(&read_input(6))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(6, Part::Two)
+        );
     }
 
     #[test]
-    fn part_two_result() {
-        assert_eq!(solution(&read_input(6)).part_two, 3120);
+    fn test_parse_error() {
+        let e = Day06::parse("mjqjpqmgbl7sphdztnvjfqwrcgsmlb").unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 11, "7"));
+
+        let e = Day06::parse("abcdefghijklmabcd\n").unwrap_err();
+
+        assert_eq!((e.column, e.expected), (18, "a start-of-message marker"));
     }
 }
@@ -1,52 +1,96 @@
-use crate::{solution::Solution, utils::parse_as};
+use crate::{day::Day, error::ParseError, utils::parse_as};
 
-pub fn main(input: &str) {
-    solution(input).print();
+pub struct Day07;
+
+impl Day for Day07 {
+    const DAY: usize = 7;
+
+    type Parsed<'a> = AllDirSizes<'a>;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        all_dir_sizes(input)
+    }
+
+    fn part_one(all_dirs: &Self::Parsed<'_>) -> Self::PartOne {
+        all_dirs.total_size_of_dirs_at_most(100000)
+    }
+
+    fn part_two(all_dirs: &Self::Parsed<'_>) -> Self::PartTwo {
+        let current_free_space = TOTAL_DISK_SPACE - all_dirs.total_space_used();
+
+        let additional_space_required =
+            TOTAL_FREE_SPACE_REQUIRED.saturating_sub(current_free_space);
+
+        all_dirs.smallest_dir_size_bigger_than(additional_space_required)
+    }
 }
 
-fn solution(input: &str) -> Solution<usize, usize> {
-    let mut path = CurPath::default();
+const TOTAL_DISK_SPACE: usize = 70000000;
+const TOTAL_FREE_SPACE_REQUIRED: usize = 30000000;
 
-    let mut part_one = 0;
+/// Replays the terminal output in `input`, recording the total size of every
+/// directory it visits
+pub fn all_dir_sizes(input: &str) -> Result<AllDirSizes<'_>, ParseError> {
+    let mut path = CurPath::default();
 
     let mut all_dirs = AllDirSizes::new();
 
-    for line in input.lines().map(OutputLine::from) {
-        match line {
+    let mut line_no = 0;
+
+    for (i, l) in input.lines().enumerate() {
+        line_no = i + 1;
+
+        let error = |expected| ParseError::new(l, l, expected).on_line(line_no);
+
+        if i == 0 && l != "$ cd /" {
+            return Err(error("`$ cd /`"));
+        }
+
+        match OutputLine::try_from(l).map_err(|e| e.on_line(line_no))? {
             OutputLine::Cd("..") => {
-                let (name, size) = path.pop().unwrap();
+                let (name, size) = path.pop().ok_or_else(|| error("a directory to leave"))?;
 
-                all_dirs.add_folder(name, size);
+                if all_dirs.is_full() {
+                    return Err(error("at most 200 directories"));
+                }
 
-                if size <= 100000 {
-                    part_one += size;
+                all_dirs.add_folder(name, size);
+            }
+            OutputLine::Cd(dir) => {
+                if path.is_full() {
+                    return Err(error("at most 14 nested directories"));
                 }
+
+                path.push(dir)
             }
-            OutputLine::Cd(dir) => path.push(dir),
             OutputLine::Ls => {}
-            OutputLine::File { size } => path.add_file_size(size),
+            OutputLine::File { size } => {
+                if size > TOTAL_DISK_SPACE - path.total_size() {
+                    return Err(error("a file that fits on the disk"));
+                }
+
+                path.add_file_size(size)
+            }
             OutputLine::Dir(_dir) => {}
         }
     }
 
-    while let Some((name, size)) = path.pop() {
-        all_dirs.add_folder(name, size);
-
-        if size <= 100000 {
-            part_one += size
-        }
+    if line_no == 0 {
+        return Err(ParseError::end_of_line("", "`$ cd /`").on_line(1));
     }
 
-    let total_disk_space = 70000000;
-    let total_free_space_required = 30000000;
-
-    let current_free_space = total_disk_space - all_dirs.total_space_used();
-
-    let additional_space_required = total_free_space_required - current_free_space;
+    while let Some((name, size)) = path.pop() {
+        if all_dirs.is_full() {
+            return Err(ParseError::end_of_line("", "at most 200 directories").on_line(line_no));
+        }
 
-    let part_two = all_dirs.smallest_dir_size_bigger_than(additional_space_required);
+        all_dirs.add_folder(name, size);
+    }
 
-    Solution { part_one, part_two }
+    Ok(all_dirs)
 }
 
 #[derive(Debug, Default)]
@@ -56,11 +100,20 @@ struct CurPath<'a> {
 }
 
 impl<'a> CurPath<'a> {
+    pub fn is_full(&self) -> bool {
+        self.i == self.path.len() - 1
+    }
+
     pub fn push(&mut self, name: &'a str) {
         self.i += 1;
         self.path[self.i] = (name, 0);
     }
 
+    /// The size of every file seen so far
+    pub fn total_size(&self) -> usize {
+        self.path[0].1
+    }
+
     pub fn add_file_size(&mut self, size: usize) {
         for x in 0..=self.i {
             self.path[x].1 += size;
@@ -78,44 +131,56 @@ impl<'a> CurPath<'a> {
     }
 }
 
-enum OutputLine<'a> {
+/// A line of the terminal session: a command, or an entry listed by `ls`
+pub enum OutputLine<'a> {
     Cd(&'a str),
     Ls,
     File { size: usize },
     Dir(&'a str),
 }
 
-impl<'a> From<&'a str> for OutputLine<'a> {
-    fn from(s: &'a str) -> Self {
+impl<'a> TryFrom<&'a str> for OutputLine<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         if s.starts_with('$') {
             if s == "$ ls" {
-                return OutputLine::Ls;
+                return Ok(OutputLine::Ls);
             }
 
             if let Some(dir) = s.strip_prefix("$ cd ") {
-                return OutputLine::Cd(dir);
+                return Ok(OutputLine::Cd(dir));
             }
+
+            Err(ParseError::new(s, s, "`$ cd <dir>` or `$ ls`"))
+        } else if let Some(dir) = s.strip_prefix("dir ") {
+            Ok(OutputLine::Dir(dir))
         } else {
-            if let Some(dir) = s.strip_prefix("dir ") {
-                return OutputLine::Dir(dir);
-            } else {
-                let (size, _name) = s.split_once(' ').unwrap();
-
-                return OutputLine::File {
-                    size: parse_as(size),
-                };
-            }
-        }
+            let (size, _name) = s
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(s, s, "`dir <name>` or `<size> <name>`"))?;
 
-        panic!("Unknown format");
+            Ok(OutputLine::File {
+                size: parse_as(s, size, "a file size")?,
+            })
+        }
     }
 }
 
-struct AllDirSizes<'a> {
+/// The name and total size (including everything nested within it) of every
+/// directory on the filesystem, up to 200 of them
+#[derive(Debug)]
+pub struct AllDirSizes<'a> {
     i: usize,
     dirs: [(&'a str, usize); 200],
 }
 
+impl Default for AllDirSizes<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> AllDirSizes<'a> {
     pub fn new() -> Self {
         Self {
@@ -124,23 +189,40 @@ impl<'a> AllDirSizes<'a> {
         }
     }
 
+    pub fn is_full(&self) -> bool {
+        self.i == self.dirs.len()
+    }
+
     pub fn add_folder(&mut self, name: &'a str, size: usize) {
         self.dirs[self.i] = (name, size);
         self.i += 1;
     }
 
+    /// Each directory's name and size, in the order they were left
+    pub fn dirs(&self) -> &[(&'a str, usize)] {
+        &self.dirs[..self.i]
+    }
+
     pub fn total_space_used(&self) -> usize {
-        (self.dirs[..self.i])
-            .into_iter()
+        self.dirs[..self.i]
+            .iter()
             .copied()
             .find(|&(name, _)| name == "/")
             .unwrap()
             .1
     }
 
+    pub fn total_size_of_dirs_at_most(&self, max_size: usize) -> usize {
+        self.dirs[..self.i]
+            .iter()
+            .map(|&(_, size)| size)
+            .filter(|&size| size <= max_size)
+            .sum()
+    }
+
     pub fn smallest_dir_size_bigger_than(&self, min_size: usize) -> usize {
-        (self.dirs[..self.i])
-            .into_iter()
+        self.dirs[..self.i]
+            .iter()
             .copied()
             .filter(|&(_, size)| size >= min_size)
             .min_by_key(|&(_, size)| size)
@@ -151,47 +233,63 @@ impl<'a> AllDirSizes<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
 
     use super::*;
 
-    const SAMPLE: &str = "
-$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k
-";
+    #[test]
+    fn test_part_one() {
+        assert_eq!(
+            Day07::solution(&read_input(7))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(7, Part::One)
+        );
+    }
 
     #[test]
-    fn part_one() {
-        assert_eq!(solution(SAMPLE.trim()).part_one, 95437);
+    fn test_part_two() {
+        assert_eq!(
+            Day07::solution(&read_input(7))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(7, Part::Two)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day07::parse("$ cd /\n$ ls\n12a4 b.txt").unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "12a4"));
+
+        let e = Day07::parse("$ cd /\n$ cd ..\n$ cd ..").unwrap_err();
+
+        assert_eq!((e.line, e.expected), (3, "a directory to leave"));
+
+        let e = Day07::parse("$ cd /\n$ rm -rf a").unwrap_err();
+
+        assert_eq!((e.line, e.column), (2, 1));
+
+        let e = Day07::parse("$ ls\n12 b.txt").unwrap_err();
+
+        assert_eq!((e.line, e.expected), (1, "`$ cd /`"));
+
+        let e = Day07::parse("").unwrap_err();
+
+        assert_eq!((e.line, e.expected), (1, "`$ cd /`"));
+
+        let e = Day07::parse("$ cd /\n$ ls\n40000000 a\n40000000 b").unwrap_err();
 
-        assert_eq!(solution(&read_input(7)).part_one, 1477771);
+        assert_eq!((e.line, e.expected), (4, "a file that fits on the disk"));
     }
 
     #[test]
-    fn part_two() {
-        assert_eq!(solution(SAMPLE.trim()).part_two, 24933642);
+    fn test_part_two_with_enough_space_already() {
+        let dirs = Day07::parse("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n500 b.txt").unwrap();
 
-        assert_eq!(solution(&read_input(7)).part_two, 3579501);
+        assert_eq!(Day07::part_two(&dirs), 500);
     }
 }
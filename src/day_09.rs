@@ -1,47 +1,53 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, str::FromStr};
 
-use crate::solution::Solution;
+use crate::{
+    day::Day,
+    error::{parse_lines, ParseError},
+    utils::parse_as,
+};
 
-pub fn main(input: &str) {
-    solution(input).print();
-}
+pub struct Day09;
 
-type Coord = (isize, isize);
+impl Day for Day09 {
+    const DAY: usize = 9;
 
-fn solution(input: &str) -> Solution<usize, usize> {
-    // Part one
-    let mut head = (0, 0);
-    let mut tail = (0, 0);
-    let mut has_visited = HashSet::new();
+    type Parsed<'a> = Vec<(Dir, usize)>;
 
-    // Part two
-    let mut many_knots = [(0, 0); 10];
-    let mut has_visited_v2 = HashSet::new();
+    type PartOne = usize;
+    type PartTwo = usize;
 
-    for (dir, steps) in input.lines().map(parse_line) {
-        for _ in 0..steps {
-            // Part one
-            update_head(&mut head, dir);
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part_one(motions: &Self::Parsed<'_>) -> Self::PartOne {
+        tail_positions_visited::<2>(motions)
+    }
+
+    fn part_two(motions: &Self::Parsed<'_>) -> Self::PartTwo {
+        tail_positions_visited::<10>(motions)
+    }
+}
 
-            update_tail(head, &mut tail);
+type Coord = (isize, isize);
 
-            has_visited.insert(tail);
+fn tail_positions_visited<const KNOTS: usize>(motions: &[(Dir, usize)]) -> usize {
+    let mut knots = [(0, 0); KNOTS];
+    let mut has_visited = HashSet::from([(0, 0)]);
 
-            // Part two
-            update_head(&mut many_knots[0], dir);
+    for &(dir, steps) in motions {
+        for _ in 0..steps {
+            update_head(&mut knots[0], dir);
 
-            for i in 1..many_knots.len() {
-                update_tail(many_knots[i - 1], &mut many_knots[i]);
+            for i in 1..knots.len() {
+                update_tail(knots[i - 1], &mut knots[i]);
             }
 
-            has_visited_v2.insert(many_knots[9]);
+            has_visited.insert(knots[KNOTS - 1]);
         }
     }
 
-    Solution {
-        part_one: has_visited.len(),
-        part_two: has_visited_v2.len(),
-    }
+    has_visited.len()
 }
 
 fn update_head(head: &mut Coord, dir: Dir) {
@@ -73,73 +79,97 @@ fn update_tail(head: Coord, tail: &mut Coord) {
     }
 }
 
-fn parse_line(line: &str) -> (Dir, usize) {
-    let (dir, steps) = line.split_once(' ').unwrap();
+/// Parses a motion like `R 4`
+pub fn parse_line(line: &str) -> Result<(Dir, usize), ParseError> {
+    let (dir, steps) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(line, line, "a direction and a number of steps"))?;
+
+    let dir = dir.parse()?;
+    let num_steps = parse_as(line, steps, "a number of steps")?;
 
-    (dir.into(), steps.parse().unwrap())
+    if num_steps > MAX_STEPS {
+        return Err(ParseError::new(line, steps, "at most 100 steps"));
+    }
+
+    Ok((dir, num_steps))
 }
 
+/// The rope moves a step at a time, so this keeps a motion from taking
+/// forever. The real inputs never move more than 20 steps at once
+const MAX_STEPS: usize = 100;
+
 #[derive(Debug, Clone, Copy)]
-enum Dir {
+pub enum Dir {
     Up,
     Down,
     Left,
     Right,
 }
 
-impl From<&str> for Dir {
-    fn from(s: &str) -> Self {
+impl FromStr for Dir {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "U" => Dir::Up,
-            "L" => Dir::Left,
-            "R" => Dir::Right,
-            "D" => Dir::Down,
-            _ => panic!("Unexpected direction"),
+            "U" => Ok(Dir::Up),
+            "L" => Ok(Dir::Left),
+            "R" => Ok(Dir::Right),
+            "D" => Ok(Dir::Down),
+            _ => Err(ParseError::new(s, s, "one of U, D, L or R")),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
 
     use super::*;
 
-    const SAMPLE: &str = "
-R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2
-";
-
     #[test]
-    fn part_one() {
-        assert_eq!(solution(SAMPLE.trim()).part_one, 13);
-
-        assert_eq!(solution(&read_input(9)).part_one, 6563);
+    fn test_part_one() {
+        assert_eq!(
+            Day09::solution(&read_input(9))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(9, Part::One)
+        );
     }
 
-    const SAMPLE_TWO: &str = "
-R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20
-";
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            Day09::solution(&read_input(9))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer(9, Part::Two)
+        );
+    }
 
     #[test]
-    fn part_two() {
-        assert_eq!(solution(SAMPLE.trim()).part_two, 1);
+    fn test_parse_error() {
+        let e = Day09::parse("R 4\nU 4\nX 3").unwrap_err();
 
-        assert_eq!(solution(SAMPLE_TWO.trim()).part_two, 36);
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "X"));
 
-        assert_eq!(solution(&read_input(9)).part_two, 2653);
+        let e = Day09::parse("R 4\nU -4").unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "-4"));
+
+        let e = Day09::parse("R 4\nR 18446744073709551615").unwrap_err();
+
+        assert_eq!(
+            (e.line, e.column, e.text.as_str()),
+            (2, 3, "18446744073709551615")
+        );
+    }
+
+    #[test]
+    fn test_start_counts_as_visited() {
+        assert_eq!(tail_positions_visited::<2>(&[]), 1);
+        assert_eq!(tail_positions_visited::<2>(&[(Dir::Right, 1)]), 1);
     }
 }
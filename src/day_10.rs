@@ -1,44 +1,79 @@
 use std::fmt::{Display, Formatter, Write};
 
-use crate::solution::Solution;
+use crate::{
+    day::Day,
+    error::{parse_lines, ParseError},
+    grid::Grid,
+    utils::parse_as,
+};
 
-pub fn main(input: &str) {
-    solution(input).print();
-}
+pub struct Day10;
 
-fn solution(input: &str) -> Solution<isize, CRT> {
-    let mut cpu = CPU::new();
+impl Day for Day10 {
+    const DAY: usize = 10;
 
-    for instr in input.lines().map(Instr::from) {
-        match instr {
-            Instr::NoOp => {
-                cpu.next_cycle();
-            }
-            Instr::Add(x) => {
-                cpu.next_cycle();
-                cpu.next_cycle();
+    type Parsed<'a> = Vec<Instr>;
 
-                cpu.add_to_register(x);
-            }
-        }
+    type PartOne = isize;
+    type PartTwo = CRT;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        // Running the program as it's read catches any that would overflow
+        let mut cpu = CPU::new();
+
+        parse_lines(input, |l| {
+            let instr = Instr::try_from(l)?;
+
+            cpu.run(instr)
+                .ok_or_else(|| ParseError::new(l, l, "an instruction that doesn't overflow"))?;
+
+            Ok(instr)
+        })
     }
 
-    Solution {
-        part_one: cpu.sum_special_signals,
-        part_two: cpu.crt,
+    fn part_one(program: &Self::Parsed<'_>) -> Self::PartOne {
+        run_program(program).sum_special_signals()
+    }
+
+    fn part_two(program: &Self::Parsed<'_>) -> Self::PartTwo {
+        run_program(program).crt().clone()
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct CPU {
+/// Runs every instruction in `program` on a new [`CPU`]
+///
+/// # Panics
+///
+/// If the register or the sum of the signal strengths overflows, which
+/// parsing rules out
+pub fn run_program(program: &[Instr]) -> CPU {
+    let mut cpu = CPU::new();
+
+    for &instr in program {
+        cpu.run(instr)
+            .expect("the register or signal strengths overflowed");
+    }
+
+    cpu
+}
+
+/// The handheld's CPU, which has a single register and drives the [`CRT`]
+#[derive(Debug, Clone)]
+pub struct CPU {
     register: isize,
     cycle_no: isize,
     sum_special_signals: isize,
     crt: CRT,
 }
 
+impl Default for CPU {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CPU {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             register: 1,
             cycle_no: 0,
@@ -47,62 +82,119 @@ impl CPU {
         }
     }
 
-    fn next_cycle(&mut self) {
+    /// Runs one instruction, or returns `None` if the register or the sum of
+    /// the signal strengths overflows
+    pub fn run(&mut self, instr: Instr) -> Option<()> {
+        match instr {
+            Instr::NoOp => self.next_cycle(),
+            Instr::Add(x) => {
+                self.next_cycle()?;
+                self.next_cycle()?;
+
+                self.register = self.register.checked_add(x)?;
+
+                Some(())
+            }
+        }
+    }
+
+    fn next_cycle(&mut self) -> Option<()> {
         self.cycle_no += 1;
 
         self.crt.next_cycle(self.register);
 
         if (self.cycle_no - 20) % 40 == 0 {
-            self.sum_special_signals += self.cycle_no * self.register;
+            let signal = self.cycle_no.checked_mul(self.register)?;
+
+            self.sum_special_signals = self.sum_special_signals.checked_add(signal)?;
         }
+
+        Some(())
+    }
+
+    pub fn register(&self) -> isize {
+        self.register
     }
 
-    fn add_to_register(&mut self, x: isize) {
-        self.register += x
+    /// The number of cycles run so far
+    pub fn cycle(&self) -> isize {
+        self.cycle_no
+    }
+
+    /// The sum of the signal strengths during the 20th cycle and every 40th
+    /// cycle after it
+    pub fn sum_special_signals(&self) -> isize {
+        self.sum_special_signals
+    }
+
+    pub fn crt(&self) -> &CRT {
+        &self.crt
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Instr {
+pub enum Instr {
     NoOp,
     Add(isize),
 }
 
-impl From<&str> for Instr {
-    fn from(s: &str) -> Self {
+impl TryFrom<&str> for Instr {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         if s == "noop" {
-            return Instr::NoOp;
+            return Ok(Instr::NoOp);
         }
 
         if let Some(x) = s.strip_prefix("addx ") {
-            return Instr::Add(x.parse().unwrap());
+            return Ok(Instr::Add(parse_as(s, x, "a number to add")?));
         }
 
-        panic!("Unknown instruction")
+        Err(ParseError::new(s, s, "`noop` or `addx <number>`"))
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct CRT {
-    pixels: [[bool; 40]; 6],
+/// A 40 by 6 pixel screen, drawing one pixel per CPU cycle
+#[derive(Debug, Clone)]
+pub struct CRT {
+    pixels: Grid<bool>,
     pos: usize,
 }
 
+impl Default for CRT {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CRT {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Self {
-            pixels: [[false; 40]; 6],
+            pixels: Grid::filled(40, 6, false),
             pos: 0,
         }
     }
 
-    fn next_cycle(&mut self, register: isize) {
-        let row = self.pos / 40;
-        let col = self.pos % 40;
+    /// Draws the next pixel, lit if the sprite centred on `register`
+    /// covers it. Once every pixel has been drawn this does nothing.
+    pub fn next_cycle(&mut self, register: isize) {
+        let col = self.pos % self.pixels.width();
+        let row = self.pos / self.pixels.width();
+
+        if let Some(pixel) = self.pixels.get_mut((col, row)) {
+            *pixel = register.abs_diff(col as isize) <= 1;
 
-        self.pixels[row][col] = col.abs_diff(register as usize) <= 1;
+            self.pos += 1;
+        }
+    }
 
-        self.pos += 1;
+    pub fn is_lit(&self, row: usize, col: usize) -> bool {
+        self.pixels[(col, row)]
+    }
+
+    /// Every pixel, `true` where it's lit
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
     }
 }
 
@@ -110,8 +202,8 @@ impl Display for CRT {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         f.write_char('\n')?;
 
-        for row in self.pixels {
-            for pixel in row {
+        for row in self.pixels.rows() {
+            for &pixel in row {
                 f.write_char(if pixel { '#' } else { '.' })?;
             }
 
@@ -124,191 +216,75 @@ impl Display for CRT {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::read_input;
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
 
     use super::*;
 
-    const SAMPLE: &str = "
-addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop
-    ";
+    #[test]
+    fn test_part_one() {
+        assert_eq!(
+            Day10::solution(&read_input(10))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer(10, Part::One)
+        );
+    }
 
     #[test]
-    fn part_one() {
-        assert_eq!(solution(SAMPLE.trim()).part_one, 13140);
+    fn test_part_two() {
+        similar_asserts::assert_eq!(
+            format!("{}", Day10::solution(&read_input(10)).unwrap().part_two),
+            known_answer(10, Part::Two)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = Day10::parse("noop\naddx 3\naddx 1O").unwrap_err();
+
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 6, "1O"));
+
+        let e = Day10::parse("noop\nmulx 3").unwrap_err();
 
-        assert_eq!(solution(&read_input(10)).part_one, 14040);
+        assert_eq!((e.line, e.column), (2, 1));
     }
 
     #[test]
-    fn part_two() {
-        let expected = "
-##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....
-";
-
-        similar_asserts::assert_eq!(format!("{}", solution(SAMPLE.trim()).part_two), expected);
-
-        let expected_two = "
-####..##...##....##.####...##.####.#....
-...#.#..#.#..#....#....#....#.#....#....
-..#..#....#.......#...#.....#.###..#....
-.#...#.##.#.......#..#......#.#....#....
-.....#..#.#..#.#..#.#....#..#.#....#....
-####..###..##...##..####..##..#....####.
-";
+    fn test_sprite_off_the_left_edge() {
+        let mut crt = CRT::new();
 
-        similar_asserts::assert_eq!(
-            format!("{}", solution(&read_input(10)).part_two),
-            expected_two
+        crt.next_cycle(-1);
+        crt.next_cycle(-1);
+
+        assert!(crt.is_lit(0, 0));
+        assert!(!crt.is_lit(0, 1));
+    }
+
+    #[test]
+    fn test_programs_longer_than_the_screen() {
+        let cpu = run_program(&[Instr::NoOp; 300]);
+
+        assert_eq!(cpu.cycle(), 300);
+        assert_eq!(
+            cpu.sum_special_signals(),
+            20 + 60 + 100 + 140 + 180 + 220 + 260 + 300
         );
     }
+
+    #[test]
+    fn test_overflow() {
+        let e = Day10::parse(&format!("addx {}\naddx 1", isize::MAX)).unwrap_err();
+
+        assert_eq!((e.line, e.column), (1, 1));
+
+        let e = Day10::parse(&format!(
+            "noop\naddx {}\n{}",
+            isize::MAX / 2,
+            "noop\n".repeat(20)
+        ))
+        .unwrap_err();
+
+        assert_eq!(e.line, 19);
+    }
 }
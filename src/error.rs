@@ -0,0 +1,179 @@
+use std::{error::Error, fmt::Display, io};
+
+/// A problem with a puzzle input, pointing at where in the input it was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The day whose input failed to parse, filled in by the day's `Solver`
+    pub day: Option<usize>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in chars
+    pub column: usize,
+    /// The text that couldn't be parsed, empty if the line ended early
+    pub text: String,
+    pub expected: &'static str,
+}
+
+impl ParseError {
+    /// An error for the `token` found somewhere within `line`. `token` must be
+    /// a slice of `line` (or empty) so its column can be worked out.
+    pub fn new(line: &str, token: &str, expected: &'static str) -> Self {
+        ParseError {
+            day: None,
+            line: 1,
+            column: column_of(line, token),
+            text: token.to_owned(),
+            expected,
+        }
+    }
+
+    /// An error for a line that ended before the `expected` text was found
+    pub fn end_of_line(line: &str, expected: &'static str) -> Self {
+        Self::new(line, &line[line.len()..], expected)
+    }
+
+    pub fn on_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+
+    pub fn in_day(self, day: usize) -> Self {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+/// 1-based char column of `token` within `line`, or just past the end of the
+/// line if `token` isn't a slice of it
+fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+
+    let offset = if offset <= line.len() && line.is_char_boundary(offset) {
+        offset
+    } else {
+        line.len()
+    };
+
+    line[..offset].chars().count() + 1
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found `{}`", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A problem with an input read a line at a time, which can fail part way
+/// through
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl ReadError {
+    pub fn in_day(self, day: usize) -> Self {
+        match self {
+            ReadError::Parse(e) => ReadError::Parse(e.in_day(day)),
+            e => e,
+        }
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "couldn't read the input: {}", e),
+            ReadError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// Parses each line of `input` with `parse_line`, recording the line number
+/// of the first line that fails.
+pub fn parse_lines<'a, T, F>(input: &'a str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| parse_line(l).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_of_token() {
+        let line = "move 3 from x to 1";
+
+        let e = ParseError::new(line, &line[12..13], "a stack number");
+
+        assert_eq!(e.column, 13);
+        assert_eq!(e.text, "x");
+    }
+
+    #[test]
+    fn test_end_of_line() {
+        let e = ParseError::end_of_line("A", "a space").on_line(4).in_day(2);
+
+        assert_eq!(
+            e.to_string(),
+            "day 2, line 4, column 2: expected a space, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_records_line_number() {
+        let result = parse_lines("1\n2\nthree\n4", |l| {
+            l.parse::<usize>()
+                .map_err(|_| ParseError::new(l, l, "a number"))
+        });
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 3, column 1: expected a number, found `three`"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::{self, read_to_string},
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{AnswersError, KnownAnswers},
+    day::Answers,
+    solution::Part,
+};
+
+/// Where the puzzle examples live, as `examples/day_NN/<name>.txt` with the
+/// expected answers beside each one in `<name>.answers`
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// One of a day's puzzle examples, with the answers the puzzle gives for it
+#[derive(Debug)]
+pub struct Example {
+    pub day: usize,
+    pub name: String,
+    pub input: String,
+    /// `None` for a part the puzzle doesn't give this example's answer to
+    pub expected: Answers,
+}
+
+impl Example {
+    /// Loads `day`'s example called `name` from `dir`. The answers file is
+    /// in the answers file format and can be left out.
+    pub fn load(dir: &Path, day: usize, name: &str) -> Result<Self, ExampleError> {
+        let path = day_dir(dir, day).join(format!("{}.txt", name));
+
+        let input = match read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(ExampleError::Missing {
+                    day,
+                    name: name.to_owned(),
+                    available: names(dir, day).unwrap_or_default(),
+                })
+            }
+            Err(e) => return Err(ExampleError::Io(path, e)),
+        };
+
+        let answers_path = path.with_extension("answers");
+
+        let known = KnownAnswers::load(&answers_path).map_err(ExampleError::Answers)?;
+
+        Ok(Example {
+            day,
+            name: name.to_owned(),
+            input,
+            expected: Answers {
+                part_one: known.get(day, Part::One).map(str::to_owned),
+                part_two: known.get(day, Part::Two).map(str::to_owned),
+            },
+        })
+    }
+
+    /// The parts of `actual` that differ from an expected answer, as the
+    /// part, the expected answer and the actual one
+    pub fn mismatches<'a>(&'a self, actual: &'a Answers) -> Vec<(Part, &'a str, &'a str)> {
+        let parts = [
+            (Part::One, &self.expected.part_one, &actual.part_one),
+            (Part::Two, &self.expected.part_two, &actual.part_two),
+        ];
+
+        parts
+            .into_iter()
+            .filter_map(|(part, expected, actual)| match (expected, actual) {
+                (Some(expected), Some(actual)) if expected != actual => {
+                    Some((part, expected.as_str(), actual.as_str()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn day_dir(dir: &Path, day: usize) -> PathBuf {
+    dir.join(format!("day_{:02}", day))
+}
+
+/// The names of `day`'s examples in `dir`, sorted, or none if the day has no
+/// examples directory
+pub fn names(dir: &Path, day: usize) -> io::Result<Vec<String>> {
+    let entries = match fs::read_dir(day_dir(dir, day)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut names = Vec::new();
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            names.extend(path.file_stem().and_then(|s| s.to_str()).map(str::to_owned));
+        }
+    }
+
+    names.sort_unstable();
+
+    Ok(names)
+}
+
+#[derive(Debug)]
+pub enum ExampleError {
+    Missing {
+        day: usize,
+        name: String,
+        available: Vec<String>,
+    },
+    Io(PathBuf, io::Error),
+    Answers(AnswersError),
+}
+
+impl Display for ExampleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExampleError::Missing { day, available, .. } if available.is_empty() => {
+                write!(f, "day {} has no examples", day)
+            }
+            ExampleError::Missing {
+                day,
+                name,
+                available,
+            } => write!(
+                f,
+                "day {} has no example called `{}`, try one of: {}",
+                day,
+                name,
+                available.join(", ")
+            ),
+            ExampleError::Io(path, e) => write!(f, "couldn't read `{}`: {}", path.display(), e),
+            ExampleError::Answers(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ExampleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dir() -> &'static Path {
+        Path::new(EXAMPLES_DIR)
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(names(dir(), 9).unwrap(), ["one", "two"]);
+        assert!(names(dir(), 25).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load() {
+        let example = Example::load(dir(), 9, "two").unwrap();
+
+        assert!(example.input.starts_with("R 5\nU 8\n"));
+        assert_eq!(example.expected.part_one, None);
+        assert_eq!(example.expected.part_two.as_deref(), Some("36"));
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let e = Example::load(dir(), 9, "three").unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "day 9 has no example called `three`, try one of: one, two"
+        );
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let example = Example::load(dir(), 9, "one").unwrap();
+
+        let actual = Answers {
+            part_one: Some("13".to_owned()),
+            part_two: Some("2".to_owned()),
+        };
+
+        assert_eq!(example.mismatches(&actual), [(Part::Two, "1", "2")]);
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::client::{Client, ClientError};
+
+/// The last day of the event, and so the last input there is to fetch
+pub const LAST_DAY: usize = 25;
+
+/// When day 1 unlocked: midnight US Eastern time on 1st December
+const FIRST_UNLOCK_SECS: u64 = 1_669_870_800;
+
+/// What fetching a single day's input did
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+/// Downloads `day`'s input into `dir`, unless it is already there
+pub fn fetch(client: &Client, dir: &Path, day: usize) -> Result<Fetched, ClientError> {
+    let path = dir.join(format!("day_{:02}.txt", day));
+
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let wait = time_until_unlock(day, SystemTime::now());
+
+    if !wait.is_zero() {
+        return Err(ClientError::NotUnlocked {
+            day,
+            wait: Some(wait),
+        });
+    }
+
+    // A new input set's directory, which the client records its requests in
+    fs::create_dir_all(dir).map_err(|e| ClientError::Write(dir.to_owned(), e))?;
+
+    let input = match client.get(&format!("day/{}/input", day)) {
+        Err(ClientError::Status(_, 404)) => Err(ClientError::NotUnlocked { day, wait: None }),
+        result => result,
+    }?;
+
+    write_atomically(&path, &input).map_err(|e| ClientError::Write(path.clone(), e))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// How long until `day`'s puzzle unlocks, or zero if it already has
+fn time_until_unlock(day: usize, now: SystemTime) -> Duration {
+    let unlock = UNIX_EPOCH + Duration::from_secs(FIRST_UNLOCK_SECS + (day as u64 - 1) * 86_400);
+
+    unlock.duration_since(now).unwrap_or_default()
+}
+
+/// Writes to a temporary file first so an interrupted download never leaves a
+/// partial input that would then be treated as cached
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let partial = path.with_extension("txt.part");
+
+    fs::write(&partial, contents)?;
+    fs::rename(&partial, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(1, FIRST_UNLOCK_SECS - 90, Duration::from_secs(90))]
+    #[test_case(1, FIRST_UNLOCK_SECS, Duration::ZERO)]
+    #[test_case(2, FIRST_UNLOCK_SECS + 3600, Duration::from_secs(23 * 3600))]
+    #[test_case(25, FIRST_UNLOCK_SECS + 30 * 86_400, Duration::ZERO)]
+    fn test_time_until_unlock(day: usize, now_secs: u64, expected: Duration) {
+        let now = UNIX_EPOCH + Duration::from_secs(now_secs);
+
+        assert_eq!(time_until_unlock(day, now), expected);
+    }
+}
@@ -0,0 +1,391 @@
+//! Random but valid puzzle inputs of any size, for stress tests and
+//! benchmarks. The same day, size and seed always give the same input.
+
+use std::io::{self, ErrorKind, Write};
+
+/// Writes an input of the given size for one day, using the random numbers
+/// from the [`Rng`]
+pub type Generator = fn(&mut Rng, usize, &mut dyn Write) -> io::Result<()>;
+
+/// Every day's generator, where day `n` is at index `n - 1`
+pub const GENERATORS: [Generator; 10] = [
+    elves, rounds, rucksacks, pairs, crates, datastream, terminal, trees, motions, program,
+];
+
+/// What `size` counts for each day, where day `n` is at index `n - 1`
+pub const SIZE_UNITS: [&str; 10] = [
+    "elves",
+    "rounds",
+    "groups of three rucksacks",
+    "pairs of elves",
+    "moves",
+    "characters",
+    "files",
+    "trees along each side of the grid",
+    "motions",
+    "instructions",
+];
+
+/// Writes the input for `day` with the given `size` and `seed` to `out`
+pub fn generate(day: usize, size: usize, seed: u64, out: &mut dyn Write) -> io::Result<()> {
+    let generator = day
+        .checked_sub(1)
+        .and_then(|i| GENERATORS.get(i))
+        .ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("there is no input generator for day {}", day),
+            )
+        })?;
+
+    generator(&mut Rng::new(seed), size, out)
+}
+
+/// A small, fast random number generator (SplitMix64), kept here rather than
+/// taken from a crate so that a seed's input never changes
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `low` to `high` inclusive
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + (self.next_u64() % (high - low + 1) as u64) as usize
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.between(0, items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.between(0, i));
+        }
+    }
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Elves carrying up to 15 items each
+fn elves(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for elf in 0..size {
+        if elf > 0 {
+            writeln!(out)?;
+        }
+
+        for _ in 0..rng.between(1, 15) {
+            writeln!(out, "{}", rng.between(1000, 70000))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn rounds(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let opponent = rng.pick(b"ABC") as char;
+        let you = rng.pick(b"XYZ") as char;
+
+        writeln!(out, "{} {}", opponent, you)?;
+    }
+
+    Ok(())
+}
+
+/// Groups of rucksacks that each take items from their own share of the item
+/// types, so each has exactly one item in both compartments and the group's
+/// badge is the only item all three carry
+fn rucksacks(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut items: Vec<u8> = [LOWERCASE, UPPERCASE].concat();
+
+    for _ in 0..size {
+        rng.shuffle(&mut items);
+
+        let badge = items[0];
+
+        for share in items[1..].chunks(17) {
+            let shared = share[0];
+            let len = rng.between(2, 8);
+
+            let mut first = share[1..len].to_vec();
+            first.push(shared);
+
+            let mut second = share[9..7 + len].to_vec();
+            second.extend([shared, badge]);
+
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+
+            if rng.between(0, 1) == 1 {
+                std::mem::swap(&mut first, &mut second);
+            }
+
+            first.extend(second);
+            first.push(b'\n');
+            out.write_all(&first)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn pairs(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let mut range = || {
+            let start = rng.between(1, 99);
+
+            (start, rng.between(start, 99))
+        };
+
+        let ((a, b), (c, d)) = (range(), range());
+
+        writeln!(out, "{}-{},{}-{}", a, b, c, d)?;
+    }
+
+    Ok(())
+}
+
+/// Nine stacks of up to four crates, then moves that can all be made. With at
+/// most 36 crates no stack can outgrow the 40 the parser allows.
+fn crates(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut heights: Vec<usize> = (0..9).map(|_| rng.between(1, 4)).collect();
+
+    for level in (0..4).rev() {
+        let row: Vec<String> = heights
+            .iter()
+            .map(|&h| {
+                if h > level {
+                    format!("[{}]", rng.pick(UPPERCASE) as char)
+                } else {
+                    "   ".to_owned()
+                }
+            })
+            .collect();
+
+        let row = row.join(" ");
+
+        if !row.trim().is_empty() {
+            writeln!(out, "{}", row)?;
+        }
+    }
+
+    writeln!(out, " 1   2   3   4   5   6   7   8   9 \n")?;
+
+    for _ in 0..size {
+        let stacks: Vec<usize> = (0..9).filter(|&i| heights[i] > 0).collect();
+
+        let from = rng.pick(&stacks);
+        let to = (from + rng.between(1, 8)) % 9;
+        let num = rng.between(1, heights[from]);
+
+        heights[from] -= num;
+        heights[to] += num;
+
+        writeln!(out, "move {} from {} to {}", num, from + 1, to + 1)?;
+    }
+
+    Ok(())
+}
+
+/// Letters from only the first half of the alphabet, so there can't be a
+/// start-of-message marker, with fourteen different letters planted
+/// somewhere in them
+fn datastream(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    let mut stream: Vec<u8> = (0..size).map(|_| rng.pick(&LOWERCASE[..13])).collect();
+
+    let mut marker = LOWERCASE.to_vec();
+    rng.shuffle(&mut marker);
+
+    let at = rng.between(0, stream.len());
+    stream.splice(at..at, marker[..14].iter().copied());
+    stream.push(b'\n');
+
+    out.write_all(&stream)
+}
+
+/// A directory in a [`terminal`] session, with its subdirectories as indexes
+struct Dir {
+    depth: usize,
+    dirs: Vec<usize>,
+    files: Vec<usize>,
+}
+
+/// A session listing a filesystem with `size` files, and a directory for
+/// every four files up to the 200 the parser allows. The files add up to no
+/// more than the disk holds.
+fn terminal(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    if size > 70_000_000 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the disk can't hold more than 70000000 files",
+        ));
+    }
+
+    let mut dirs = vec![Dir {
+        depth: 0,
+        dirs: Vec::new(),
+        files: Vec::new(),
+    }];
+
+    // The parser allows 200 directories, nested up to 13 below the root
+    for i in 1..(size / 4).clamp(1, 200) {
+        let parent = loop {
+            let parent = rng.between(0, i - 1);
+
+            if dirs[parent].depth < 12 {
+                break parent;
+            }
+        };
+
+        dirs.push(Dir {
+            depth: dirs[parent].depth + 1,
+            dirs: Vec::new(),
+            files: Vec::new(),
+        });
+        dirs[parent].dirs.push(i);
+    }
+
+    let max_file_size = 70_000_000 / size.max(1);
+
+    for _ in 0..size {
+        let dir = rng.between(0, dirs.len() - 1);
+
+        dirs[dir].files.push(rng.between(1, max_file_size));
+    }
+
+    writeln!(out, "$ cd /")?;
+
+    // Visits each directory depth first, listing it on the way in
+    let mut stack = vec![(0, 0)];
+
+    while let Some((i, next_child)) = stack.pop() {
+        let dir = &dirs[i];
+
+        if next_child == 0 {
+            writeln!(out, "$ ls")?;
+
+            for child in &dir.dirs {
+                writeln!(out, "dir d{}", child)?;
+            }
+
+            for (n, size) in dir.files.iter().enumerate() {
+                writeln!(out, "{} f{}.txt", size, n)?;
+            }
+        }
+
+        match dir.dirs.get(next_child) {
+            Some(&child) => {
+                writeln!(out, "$ cd d{}", child)?;
+
+                stack.push((i, next_child + 1));
+                stack.push((child, 0));
+            }
+            None if i != 0 => writeln!(out, "$ cd ..")?,
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn trees(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let mut row: Vec<u8> = (0..size).map(|_| b'0' + rng.between(0, 9) as u8).collect();
+        row.push(b'\n');
+
+        out.write_all(&row)?;
+    }
+
+    Ok(())
+}
+
+fn motions(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        let dir = rng.pick(b"UDLR") as char;
+
+        writeln!(out, "{} {}", dir, rng.between(1, 20))?;
+    }
+
+    Ok(())
+}
+
+/// A program that adds small numbers to the register, so it wanders but
+/// never overflows
+fn program(rng: &mut Rng, size: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..size {
+        match rng.between(0, 2) {
+            0 => writeln!(out, "noop")?,
+            _ => writeln!(out, "addx {}", rng.between(0, 20) as isize - 10)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generated(day: usize, size: usize, seed: u64) -> String {
+        let mut out = Vec::new();
+
+        generate(day, size, seed, &mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(1);
+        let numbers: Vec<_> = (0..1000).map(|_| rng.between(3, 5)).collect();
+
+        assert!(numbers.iter().all(|n| (3..=5).contains(n)));
+        assert!([3, 4, 5].iter().all(|n| numbers.contains(n)));
+
+        let mut items: Vec<_> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for day in 1..=GENERATORS.len() {
+            assert_eq!(generated(day, 50, 7), generated(day, 50, 7));
+            assert_ne!(generated(day, 50, 7), generated(day, 50, 8));
+        }
+    }
+
+    #[test]
+    fn test_days_without_a_generator() {
+        for day in [0, GENERATORS.len() + 1] {
+            let e = generate(day, 10, 0, &mut Vec::new()).unwrap_err();
+
+            assert_eq!(e.kind(), ErrorKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(generated(1, 30, 0).split("\n\n").count(), 30);
+        assert_eq!(generated(3, 30, 0).lines().count(), 90);
+        assert_eq!(generated(6, 30, 0).trim_end().len(), 44);
+        assert_eq!(generated(7, 30, 0).matches(".txt").count(), 30);
+        assert_eq!(generated(8, 30, 0).lines().count(), 30);
+        assert_eq!(generated(10, 30, 0).lines().count(), 30);
+    }
+}
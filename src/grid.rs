@@ -0,0 +1,362 @@
+use std::{
+    fmt::{Display, Formatter},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::ParseError;
+
+/// A position in a [`Grid`] as `(x, y)`, where `(0, 0)` is the top left
+pub type Coord = (usize, usize);
+
+/// A rectangular grid of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given size from its `cells` in row order
+    ///
+    /// # Panics
+    ///
+    /// If there aren't exactly `width * height` cells
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![cell; width * height])
+    }
+
+    /// Parses one cell per char with `parse_cell`, and one row per line with
+    /// either line ending. Every row must be as long as the first, and
+    /// trailing blank lines are ignored. `expected` describes a cell for the
+    /// errors.
+    pub fn parse<F>(
+        input: &str,
+        expected: &'static str,
+        mut parse_cell: F,
+    ) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut lines: Vec<_> = input.lines().collect();
+
+        while lines.last() == Some(&"") {
+            lines.pop();
+        }
+
+        let width = lines.first().map_or(0, |l| l.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (i, line) in lines.iter().enumerate() {
+            let error = |token, expected| ParseError::new(line, token, expected).on_line(i + 1);
+
+            let mut row_len = 0;
+
+            for (j, c) in line.char_indices() {
+                let token = &line[j..j + c.len_utf8()];
+
+                if row_len == width {
+                    return Err(error(token, "end of line"));
+                }
+
+                cells.push(parse_cell(c).ok_or_else(|| error(token, expected))?);
+                row_len += 1;
+            }
+
+            if row_len < width {
+                return Err(error(&line[line.len()..], expected));
+            }
+        }
+
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` if that's outside the grid
+    pub fn get(&self, (x, y): Coord) -> Option<&T> {
+        self.contains((x, y))
+            .then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Coord) -> Option<&mut T> {
+        self.contains((x, y))
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Every coordinate in the grid, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        // `chunks_exact` doesn't allow a width of 0
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// The cells in column `x`, from top to bottom
+    pub fn column(&self, x: usize) -> impl ExactSizeIterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The coordinate one step from `coord` in `dir`, if it's in the grid
+    pub fn step(&self, (x, y): Coord, dir: Direction) -> Option<Coord> {
+        let (dx, dy) = dir.offset();
+
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(next).then_some(next)
+    }
+
+    /// The coordinates from `from` (but not including it) to the edge of the
+    /// grid in `dir`
+    pub fn ray(&self, from: Coord, dir: Direction) -> impl Iterator<Item = Coord> + '_ {
+        iter::successors(self.step(from, dir), move |&c| self.step(c, dir))
+    }
+
+    /// The coordinates next to `coord` in each of `dirs`, usually
+    /// [`Direction::ORTHOGONAL`] or [`Direction::ALL`], that are in the grid
+    pub fn neighbours<const N: usize>(
+        &self,
+        coord: Coord,
+        dirs: [Direction; N],
+    ) -> impl Iterator<Item = Coord> + '_ {
+        dirs.into_iter()
+            .filter_map(move |dir| self.step(coord, dir))
+    }
+
+    /// The grid flipped over its top-left to bottom-right diagonal, so rows
+    /// become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+/// Each row on its own line
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// One of the eight directions from a cell to its neighbours, where north is
+/// up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The change in `(x, y)` from one step in this direction
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    fn grid() -> Grid<u32> {
+        digits("123\n456\n").unwrap()
+    }
+
+    #[test_case("123\n456\n")]
+    #[test_case("123\r\n456\r\n")]
+    #[test_case("123\n456")]
+    #[test_case("123\n456\n\n\n")]
+    fn test_parse(input: &str) {
+        let grid = digits(input).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+    }
+
+    #[test_case("123\n4x6", 2, 2, "x", "a digit")]
+    #[test_case("123\n45", 2, 3, "", "a digit")]
+    #[test_case("123\n4567", 2, 4, "7", "end of line")]
+    fn test_parse_error(input: &str, line: usize, column: usize, text: &str, expected: &str) {
+        let e = digits(input).unwrap_err();
+
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.expected),
+            (line, column, text, expected)
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let grid = digits("").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().len(), 0);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = grid();
+
+        assert_eq!(grid.get((0, 1)), Some(&4));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+
+        *grid.get_mut((1, 1)).unwrap() = 0;
+        grid[(0, 0)] = 9;
+
+        assert_eq!(grid.to_string(), "923\n406\n");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+
+        let columns: Vec<Vec<_>> = grid.columns().map(|c| c.copied().collect()).collect();
+
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(
+            grid.coords().collect::<Vec<_>>(),
+            [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+        );
+    }
+
+    #[test_case((0, 0), Direction::East, &[(1, 0), (2, 0)])]
+    #[test_case((2, 1), Direction::West, &[(1, 1), (0, 1)])]
+    #[test_case((0, 0), Direction::North, &[])]
+    #[test_case((0, 0), Direction::SouthEast, &[(1, 1)])]
+    fn test_ray(from: Coord, dir: Direction, expected: &[Coord]) {
+        assert_eq!(grid().ray(from, dir).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours((1, 0), Direction::ORTHOGONAL)
+                .collect::<Vec<_>>(),
+            [(2, 0), (1, 1), (0, 0)]
+        );
+        assert_eq!(grid.neighbours((1, 0), Direction::ALL).count(), 5);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let transposed = grid().transpose();
+
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.to_string(), "14\n25\n36\n");
+        assert_eq!(transposed.transpose(), grid());
+    }
+}
@@ -1,26 +1,384 @@
-pub fn groups_of<const N: usize, I>(iter: I) -> GroupsOf<N, I> {
-    GroupsOf { iter }
+use std::{array, iter::FusedIterator, marker::PhantomData};
+
+/// Splits `iter` into groups of `N` items. Any items left over at the end are
+/// kept back rather than yielded as a short group, see
+/// [`GroupsOf::into_remainder`].
+///
+/// Groups of no items are rejected when the program is built:
+///
+/// ```compile_fail
+/// advent_of_code_2022::groups_of::groups_of::<0, _>([1, 2, 3]);
+/// ```
+pub fn groups_of<const N: usize, I>(iter: I) -> GroupsOf<N, I::IntoIter>
+where
+    I: IntoIterator,
+{
+    const { assert!(N > 0, "groups must have at least one item") };
+
+    GroupsOf {
+        iter: iter.into_iter(),
+        buffer: array::from_fn(|_| None),
+        len: 0,
+    }
+}
+
+pub struct GroupsOf<const N: usize, I: Iterator> {
+    iter: I,
+    /// The start of a group that the iterator ran out part way through
+    buffer: [Option<I::Item>; N],
+    len: usize,
+}
+
+impl<const N: usize, I: Iterator> GroupsOf<N, I> {
+    /// The items that didn't make up a whole group, fewer than `N` of them,
+    /// once the iterator has run out
+    pub fn into_remainder(self) -> Vec<I::Item> {
+        self.buffer.into_iter().flatten().collect()
+    }
+}
+
+impl<const N: usize, I: Iterator> Iterator for GroupsOf<N, I> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.len < N {
+            self.buffer[self.len] = Some(self.iter.next()?);
+            self.len += 1;
+        }
+
+        self.len = 0;
+
+        Some(array::from_fn(|i| self.buffer[i].take().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        let groups = |n: usize| n.saturating_add(self.len) / N;
+
+        (
+            groups(lower),
+            upper.and_then(|n| n.checked_add(self.len)).map(|n| n / N),
+        )
+    }
 }
 
-pub struct GroupsOf<const N: usize, I> {
+impl<const N: usize, I: ExactSizeIterator> ExactSizeIterator for GroupsOf<N, I> {}
+
+impl<const N: usize, I: FusedIterator> FusedIterator for GroupsOf<N, I> {}
+
+/// Every run of `N` consecutive items in `iter`, overlapping each other.
+/// Windows of no items are rejected when the program is built:
+///
+/// ```compile_fail
+/// advent_of_code_2022::groups_of::windows::<0, _>([1, 2, 3]);
+/// ```
+pub fn windows<const N: usize, I>(iter: I) -> Windows<N, I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Clone,
+{
+    const { assert!(N > 0, "windows must have at least one item") };
+
+    Windows {
+        iter: iter.into_iter(),
+        window: None,
+    }
+}
+
+pub struct Windows<const N: usize, I: Iterator> {
     iter: I,
+    window: Option<[I::Item; N]>,
 }
 
-impl<const N: usize, I, T> Iterator for GroupsOf<N, I>
+impl<const N: usize, I> Iterator for Windows<N, I>
 where
-    I: Iterator<Item = T>,
-    T: Default,
-    T: Copy,
+    I: Iterator,
+    I::Item: Clone,
 {
-    type Item = [T; N];
+    type Item = [I::Item; N];
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut items = [Default::default(); N];
+        match &mut self.window {
+            Some(window) => {
+                let item = self.iter.next()?;
+
+                window.rotate_left(1);
+                window[N - 1] = item;
+            }
+            None => self.window = Some(groups_of(&mut self.iter).next()?),
+        }
+
+        self.window.clone()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
 
-        for i in 0..N {
-            items[i] = self.iter.next()?;
+        match self.window {
+            Some(_) => (lower, upper),
+            None => (
+                lower.saturating_sub(N - 1),
+                upper.map(|n| n.saturating_sub(N - 1)),
+            ),
         }
+    }
+}
+
+impl<const N: usize, I> ExactSizeIterator for Windows<N, I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+/// A tuple of `N` items of the same type, which [`tuples`] can yield
+pub trait Tuple<const N: usize, T> {
+    fn from_array(items: [T; N]) -> Self;
+}
+
+impl<T> Tuple<2, T> for (T, T) {
+    fn from_array([a, b]: [T; 2]) -> Self {
+        (a, b)
+    }
+}
+
+impl<T> Tuple<3, T> for (T, T, T) {
+    fn from_array([a, b, c]: [T; 3]) -> Self {
+        (a, b, c)
+    }
+}
+
+impl<T> Tuple<4, T> for (T, T, T, T) {
+    fn from_array([a, b, c, d]: [T; 4]) -> Self {
+        (a, b, c, d)
+    }
+}
+
+/// Like [`groups_of`], but with each group as a tuple, so the size can come
+/// from a pattern like `for (a, b) in tuples(iter)`
+pub fn tuples<const N: usize, I, T>(iter: I) -> Tuples<N, I::IntoIter, T>
+where
+    I: IntoIterator,
+    T: Tuple<N, I::Item>,
+{
+    Tuples {
+        groups: groups_of(iter),
+        tuple: PhantomData,
+    }
+}
+
+pub struct Tuples<const N: usize, I: Iterator, T> {
+    groups: GroupsOf<N, I>,
+    tuple: PhantomData<fn() -> T>,
+}
+
+impl<const N: usize, I: Iterator, T> Tuples<N, I, T> {
+    /// See [`GroupsOf::into_remainder`]
+    pub fn into_remainder(self) -> Vec<I::Item> {
+        self.groups.into_remainder()
+    }
+}
+
+impl<const N: usize, I, T> Iterator for Tuples<N, I, T>
+where
+    I: Iterator,
+    T: Tuple<N, I::Item>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.groups.next().map(T::from_array)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.groups.size_hint()
+    }
+}
+
+impl<const N: usize, I, T> ExactSizeIterator for Tuples<N, I, T>
+where
+    I: ExactSizeIterator,
+    T: Tuple<N, I::Item>,
+{
+}
+
+/// The adapters in this module as iterator methods
+pub trait GroupsOfExt: Iterator + Sized {
+    fn groups_of<const N: usize>(self) -> GroupsOf<N, Self> {
+        groups_of(self)
+    }
+
+    fn windows<const N: usize>(self) -> Windows<N, Self>
+    where
+        Self::Item: Clone,
+    {
+        windows(self)
+    }
+
+    fn tuples<const N: usize, T>(self) -> Tuples<N, Self, T>
+    where
+        T: Tuple<N, Self::Item>,
+    {
+        tuples(self)
+    }
+}
+
+impl<I: Iterator> GroupsOfExt for I {}
+
+/// Splits `input` into blocks of lines separated by a blank line. Each blank
+/// line after the first in a row gives an empty block.
+pub fn split_on_blank_lines(input: &str) -> SplitOnBlankLines<'_> {
+    SplitOnBlankLines {
+        input,
+        pos: 0,
+        line: 1,
+    }
+}
+
+pub struct SplitOnBlankLines<'a> {
+    input: &'a str,
+    pos: usize,
+    line: usize,
+}
+
+/// Some lines of the input, without the line break after the last one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// The number of the block's first line, starting from 1
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    /// The block's lines, each with its line number
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let first = self.line;
+
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, l)| (first + i, l))
+    }
+}
+
+impl<'a> Iterator for SplitOnBlankLines<'a> {
+    type Item = Block<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.input.len() {
+            return None;
+        }
+
+        let start = self.pos;
+        let line = self.line;
+        let mut end = start;
+
+        for l in self.input[start..].split_inclusive('\n') {
+            self.pos += l.len();
+            self.line += 1;
+
+            if l == "\n" || l == "\r\n" {
+                break;
+            }
+
+            end = self.pos;
+        }
+
+        let text = &self.input[start..end];
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+
+        Some(Block { line, text })
+    }
+}
+
+impl FusedIterator for SplitOnBlankLines<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test]
+    fn test_groups_of() {
+        let mut groups = groups_of::<3, _>(1..9);
+
+        assert_eq!(groups.size_hint(), (2, Some(2)));
+        assert_eq!(groups.next(), Some([1, 2, 3]));
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups.next(), Some([4, 5, 6]));
+        assert_eq!(groups.next(), None);
+        assert_eq!(groups.into_remainder(), [7, 8]);
+    }
+
+    #[test]
+    fn test_groups_of_non_copy() {
+        let words = ["a", "b", "c", "d"].map(String::from);
+
+        let groups: Vec<_> = words.into_iter().groups_of::<2>().collect();
+
+        assert_eq!(groups, [["a", "b"], ["c", "d"]]);
+    }
+
+    #[test]
+    fn test_groups_of_no_remainder() {
+        let mut groups = (1..=4).groups_of::<2>();
+
+        assert_eq!(groups.by_ref().count(), 2);
+        assert!(groups.into_remainder().is_empty());
+    }
+
+    #[test]
+    fn test_windows() {
+        let windows = ['a', 'b', 'c', 'd'].into_iter().windows::<3>();
+
+        assert_eq!(windows.len(), 2);
+        assert_eq!(
+            windows.collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['b', 'c', 'd']]
+        );
+    }
+
+    #[test]
+    fn test_windows_too_short() {
+        assert_eq!((1..3).windows::<3>().len(), 0);
+        assert_eq!((1..=2).windows::<3>().next(), None);
+    }
+
+    #[test]
+    fn test_tuples() {
+        let mut pairs = "1-3,5-7".split([',', '-']).tuples();
+
+        assert_eq!(pairs.next(), Some(("1", "3")));
+        assert_eq!(pairs.next(), Some(("5", "7")));
+        assert_eq!(pairs.next(), None);
+
+        let mut triples = (1..=7).tuples();
+
+        assert_eq!(triples.next(), Some((1, 2, 3)));
+        assert_eq!(triples.by_ref().count(), 1);
+        assert_eq!(triples.into_remainder(), [7]);
+    }
+
+    #[test_case("", &[])]
+    #[test_case("a\nb\n", &[(1, "a\nb")])]
+    #[test_case("a\nb\n\nc", &[(1, "a\nb"), (4, "c")])]
+    #[test_case("a\n\n\nb\n\n", &[(1, "a"), (3, ""), (4, "b")])]
+    #[test_case("a\r\n\r\nb\r\n", &[(1, "a"), (3, "b")])]
+    fn test_split_on_blank_lines(input: &str, expected: &[(usize, &str)]) {
+        let blocks: Vec<_> = split_on_blank_lines(input)
+            .map(|b| (b.line, b.text))
+            .collect();
+
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn test_block_lines() {
+        let block = split_on_blank_lines("a\n\nb\nc").nth(1).unwrap();
 
-        Some(items)
+        assert_eq!(block.lines().collect::<Vec<_>>(), [(3, "b"), (4, "c")]);
     }
 }
@@ -0,0 +1,239 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::{read_to_string, File},
+    io::{self, BufRead, BufReader, ErrorKind, Read},
+    path::PathBuf,
+};
+
+use flate2::bufread::MultiGzDecoder;
+
+use crate::answers::ANSWERS_FILE;
+
+/// Environment variable that overrides the default inputs directory
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// The bytes every gzip file starts with
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Where the puzzle inputs are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inputs {
+    /// A directory of `day_NN.txt` files
+    Dir(PathBuf),
+    /// A single input piped in on stdin
+    Stdin,
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Inputs::Dir(DEFAULT_INPUTS_DIR.into())
+    }
+}
+
+impl Inputs {
+    /// The inputs in `dir` (or stdin if it is `-`), optionally within the
+    /// named input `set`, such as `inputs/alice`
+    pub fn new(dir: &str, set: Option<&str>) -> Self {
+        if dir == "-" {
+            return Inputs::Stdin;
+        }
+
+        let mut dir = PathBuf::from(dir);
+
+        dir.extend(set);
+
+        Inputs::Dir(dir)
+    }
+
+    pub fn path(&self, day: usize) -> Option<PathBuf> {
+        match self {
+            Inputs::Dir(dir) => Some(dir.join(format!("day_{:02}.txt", day))),
+            Inputs::Stdin => None,
+        }
+    }
+
+    /// The answers file that goes with these inputs, if they come from a
+    /// directory
+    pub fn answers_path(&self) -> Option<PathBuf> {
+        match self {
+            Inputs::Dir(dir) => Some(dir.join(ANSWERS_FILE)),
+            Inputs::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: usize) -> Result<String, InputError> {
+        let path = self.path(day);
+
+        let result = match &path {
+            Some(path) => read_to_string(path),
+            None => {
+                let mut input = String::new();
+
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|source| InputError { day, path, source })
+    }
+
+    /// Opens `day`'s input to be read a bit at a time rather than all at once,
+    /// decompressing it on the way if it's gzipped
+    pub fn open(&self, day: usize) -> Result<Box<dyn BufRead>, InputError> {
+        let path = self.path(day);
+
+        let result = match &path {
+            Some(path) => File::open(path).and_then(|file| decompressed(BufReader::new(file))),
+            None => decompressed(io::stdin().lock()),
+        };
+
+        result.map_err(|source| InputError { day, path, source })
+    }
+
+    /// Reads all of `day`'s input through [`Inputs::open`], so it's
+    /// decompressed if it's gzipped, then [`normalise`]s it
+    pub fn read_decompressed(&self, day: usize) -> Result<String, InputError> {
+        let mut input = String::new();
+
+        self.open(day)?
+            .read_to_string(&mut input)
+            .map_err(|source| InputError {
+                day,
+                path: self.path(day),
+                source,
+            })?;
+
+        Ok(normalise(&input))
+    }
+
+    /// Reads `day`'s input like [`Inputs::read`], then [`normalise`]s it
+    pub fn read_normalised(&self, day: usize) -> Result<String, InputError> {
+        self.read(day).map(|input| normalise(&input))
+    }
+}
+
+/// `reader`, decompressed if it starts like a gzip file does
+pub fn decompressed<R: BufRead + 'static>(mut reader: R) -> io::Result<Box<dyn BufRead>> {
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        return Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))));
+    }
+
+    Ok(Box::new(reader))
+}
+
+/// `input` without a byte order mark, with CRLF line endings turned into LF
+/// and trailing whitespace trimmed from every line, as some editors leave
+/// them. Leading whitespace is kept, as day 5's stack diagram relies on it.
+pub fn normalise(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let mut normalised = String::with_capacity(input.len());
+
+    for line in input.split_inclusive('\n') {
+        normalised.push_str(line.trim_end());
+
+        if line.ends_with('\n') {
+            normalised.push('\n');
+        }
+    }
+
+    normalised
+}
+
+/// A puzzle input that couldn't be read
+#[derive(Debug)]
+pub struct InputError {
+    pub day: usize,
+    /// The file that was tried, or `None` for stdin
+    pub path: Option<PathBuf>,
+    pub source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) if self.source.kind() == ErrorKind::NotFound => write!(
+                f,
+                "no input for day {}, expected a file at `{}`",
+                self.day,
+                path.display()
+            ),
+            Some(path) => write!(f, "couldn't read `{}`: {}", path.display(), self.source),
+            None => write!(
+                f,
+                "couldn't read the input for day {} from stdin: {}",
+                self.day, self.source
+            ),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case("1000\n\n2000\n", "1000\n\n2000\n")]
+    #[test_case("1000\r\n\r\n2000\r\n", "1000\n\n2000\n")]
+    #[test_case("\u{feff}1000\n2000", "1000\n2000")]
+    #[test_case("1000 \n \t\n2000\t\r\n", "1000\n\n2000\n")]
+    #[test_case("    [D]    \r\n[N] [C]    \r\n", "    [D]\n[N] [C]\n")]
+    fn test_normalise(input: &str, expected: &str) {
+        assert_eq!(normalise(input), expected);
+    }
+
+    #[test]
+    fn test_decompressed() {
+        let mut gzipped = GzEncoder::new(Vec::new(), Compression::fast());
+        gzipped.write_all(b"1000\n\n2000\n").unwrap();
+
+        let mut input = String::new();
+
+        decompressed(Cursor::new(gzipped.finish().unwrap()))
+            .unwrap()
+            .read_to_string(&mut input)
+            .unwrap();
+
+        assert_eq!(input, "1000\n\n2000\n");
+
+        input.clear();
+
+        decompressed(Cursor::new(b"1000\n".to_vec()))
+            .unwrap()
+            .read_to_string(&mut input)
+            .unwrap();
+
+        assert_eq!(input, "1000\n");
+    }
+
+    #[test_case("inputs", None, Some("inputs/day_07.txt"))]
+    #[test_case("inputs", Some("alice"), Some("inputs/alice/day_07.txt"))]
+    #[test_case("/tmp/aoc", None, Some("/tmp/aoc/day_07.txt"))]
+    #[test_case("-", None, None)]
+    fn test_path(dir: &str, set: Option<&str>, expected: Option<&str>) {
+        assert_eq!(Inputs::new(dir, set).path(7), expected.map(PathBuf::from));
+    }
+
+    #[test]
+    fn test_missing_file_names_path() {
+        let e = Inputs::new("inputs", Some("nobody")).read(7).unwrap_err();
+
+        assert_eq!(
+            e.to_string(),
+            "no input for day 7, expected a file at `inputs/nobody/day_07.txt`"
+        );
+    }
+}
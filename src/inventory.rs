@@ -0,0 +1,348 @@
+//! A report on what every elf carries on day 1, for working out how to share
+//! the snacks out more evenly.
+
+use std::fmt::Write;
+
+use crate::day_01::Elf;
+
+/// How many bars the histogram of totals has, at most
+const HISTOGRAM_BINS: usize = 10;
+
+/// The width of the histogram's longest bar
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Every elf's items, with statistics on their totals
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub elves: Vec<Elf>,
+    /// `None` when there are no elves
+    pub stats: Option<Stats>,
+    pub histogram: Histogram,
+}
+
+impl Report {
+    pub fn new(elves: Vec<Elf>) -> Self {
+        let totals: Vec<usize> = elves.iter().map(|elf| elf.total).collect();
+
+        Report {
+            stats: Stats::of(&totals),
+            histogram: Histogram::of(&totals, HISTOGRAM_BINS),
+            elves,
+        }
+    }
+
+    /// A table of the elves followed by the statistics and the histogram
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "{:>5} {:>6} {:>9} {:>8} {:>8}\n",
+            "Elf", "Items", "Total", "Largest", "Smallest"
+        );
+
+        for elf in &self.elves {
+            writeln!(
+                text,
+                "{:>5} {:>6} {:>9} {:>8} {:>8}",
+                elf.number, elf.items, elf.total, elf.largest, elf.smallest
+            )
+            .unwrap();
+        }
+
+        writeln!(text, "\nElves: {}", self.elves.len()).unwrap();
+
+        let Some(stats) = &self.stats else {
+            return text;
+        };
+
+        writeln!(text, "Mean total: {:.1}", stats.mean).unwrap();
+        writeln!(text, "Median total: {:.1}", stats.median).unwrap();
+        writeln!(text, "Standard deviation: {:.1}", stats.std_dev).unwrap();
+
+        text.push('\n');
+        text += &self.histogram.to_text();
+
+        text
+    }
+
+    /// A JSON object with a list of the elves, the statistics (`null` if there
+    /// are no elves) and the histogram's bars
+    pub fn to_json(&self) -> String {
+        let elves: Vec<String> = self
+            .elves
+            .iter()
+            .map(|elf| {
+                format!(
+                    "{{\"elf\":{},\"items\":{},\"total\":{},\"largest\":{},\"smallest\":{}}}",
+                    elf.number, elf.items, elf.total, elf.largest, elf.smallest
+                )
+            })
+            .collect();
+
+        let stats = match &self.stats {
+            Some(stats) => format!(
+                "{{\"mean\":{},\"median\":{},\"std_dev\":{}}}",
+                stats.mean, stats.median, stats.std_dev
+            ),
+            None => "null".to_owned(),
+        };
+
+        let bars: Vec<String> = self
+            .histogram
+            .bars()
+            .map(|(from, to, elves)| {
+                format!("{{\"from\":{},\"to\":{},\"elves\":{}}}", from, to, elves)
+            })
+            .collect();
+
+        format!(
+            "{{\"elves\":[{}],\"stats\":{},\"histogram\":[{}]}}",
+            elves.join(","),
+            stats,
+            bars.join(",")
+        )
+    }
+}
+
+/// Statistics on the elves' totals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    /// The population standard deviation, since the elves are all the elves
+    /// there are
+    pub std_dev: f64,
+}
+
+impl Stats {
+    pub fn of(totals: &[usize]) -> Option<Self> {
+        if totals.is_empty() {
+            return None;
+        }
+
+        let len = totals.len() as f64;
+        let mean = totals.iter().map(|&t| t as f64).sum::<f64>() / len;
+
+        let variance = totals
+            .iter()
+            .map(|&t| (t as f64 - mean).powi(2))
+            .sum::<f64>()
+            / len;
+
+        let mut sorted = totals.to_vec();
+        sorted.sort_unstable();
+
+        let mid = sorted.len() / 2;
+
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] as f64 + sorted[mid] as f64) / 2.0
+        } else {
+            sorted[mid] as f64
+        };
+
+        Some(Stats {
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+        })
+    }
+}
+
+/// How many totals fall into each of a run of equally wide ranges
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    /// The smallest total in the first bar
+    pub start: usize,
+    /// How many different totals each bar covers
+    pub width: usize,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// Splits the range from the smallest total to the biggest into at most
+    /// `bins` bars
+    pub fn of(totals: &[usize], bins: usize) -> Self {
+        let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+            return Histogram {
+                start: 0,
+                width: 1,
+                counts: Vec::new(),
+            };
+        };
+
+        let width = ((max - min) / bins.max(1)) + 1;
+
+        let mut counts = vec![0; (max - min) / width + 1];
+
+        for &t in totals {
+            counts[(t - min) / width] += 1;
+        }
+
+        Histogram {
+            start: min,
+            width,
+            counts,
+        }
+    }
+
+    /// Each bar's smallest and biggest total and how many elves are in it
+    pub fn bars(&self) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.counts.iter().enumerate().map(|(i, &count)| {
+            let from = self.start + i * self.width;
+
+            // The last bar can reach past the biggest total there could be
+            (from, from.saturating_add(self.width - 1), count)
+        })
+    }
+
+    /// A line for each bar, scaled so the longest is [`HISTOGRAM_WIDTH`] long
+    pub fn to_text(&self) -> String {
+        let most = self.counts.iter().copied().max().unwrap_or(0);
+
+        let mut text = String::new();
+
+        for (from, to, count) in self.bars() {
+            // Any elves at all get at least one mark
+            let len = (count * HISTOGRAM_WIDTH).div_ceil(most.max(1));
+
+            writeln!(
+                text,
+                "{:>9} - {:>9} | {:<width$} {}",
+                from,
+                to,
+                "#".repeat(len),
+                count,
+                width = HISTOGRAM_WIDTH
+            )
+            .unwrap();
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        day_01::{self, Elf},
+        utils::read_input,
+    };
+
+    use super::*;
+
+    use similar_asserts::assert_eq;
+
+    const SAMPLE: &str = include_str!("../examples/day_01/one.txt");
+
+    fn report(input: &str) -> Report {
+        Report::new(day_01::elves(input).map(Result::unwrap).collect())
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = report(SAMPLE.trim()).stats.unwrap();
+
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert!((stats.std_dev - 6985.70).abs() < 0.01);
+
+        assert_eq!(Stats::of(&[1, 4, 2, 3]).unwrap().median, 2.5);
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let histogram = Histogram::of(&[6000, 4000, 11000, 24000, 10000], 10);
+
+        assert_eq!((histogram.start, histogram.width), (4000, 2001));
+        assert_eq!(histogram.counts, [2, 0, 1, 1, 0, 0, 0, 0, 0, 1]);
+
+        let histogram = Histogram::of(&[7, 7, 7], 10);
+
+        assert_eq!(histogram.bars().collect::<Vec<_>>(), [(7, 7, 3)]);
+
+        let histogram = Histogram::of(&[1, 2, 3], 10);
+
+        assert_eq!(histogram.counts, [1, 1, 1]);
+
+        let histogram = Histogram::of(&[usize::MAX], 10);
+
+        assert_eq!(
+            histogram.bars().collect::<Vec<_>>(),
+            [(usize::MAX, usize::MAX, 1)]
+        );
+
+        let histogram = Histogram::of(&[0, usize::MAX], 10);
+
+        assert_eq!(
+            histogram.bars().last(),
+            Some((9 * histogram.width, usize::MAX, 1))
+        );
+    }
+
+    #[test]
+    fn test_every_elf_is_in_the_histogram() {
+        let elves: Vec<Elf> = day_01::elves(&read_input(1)).map(Result::unwrap).collect();
+        let report = Report::new(elves);
+
+        assert!(report.histogram.counts.len() <= HISTOGRAM_BINS);
+        assert_eq!(
+            report.histogram.counts.iter().sum::<usize>(),
+            report.elves.len()
+        );
+    }
+
+    #[test]
+    fn test_to_text() {
+        let expected = "  \
+  Elf  Items     Total  Largest Smallest
+    1      2         2        1        1
+    2      1         4        4        4
+
+Elves: 2
+Mean total: 3.0
+Median total: 3.0
+Standard deviation: 1.0
+
+        2 -         2 | ######################################## 1
+        3 -         3 |                                          0
+        4 -         4 | ######################################## 1
+";
+
+        assert_eq!(report("1\n1\n\n4").to_text(), expected);
+        assert_eq!(
+            report("").to_text(),
+            format!("{}\n\nElves: 0\n", expected.lines().next().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_biggest_total() {
+        let input = format!("{}\n", usize::MAX);
+        let report = report(&input);
+
+        assert_eq!(report.histogram.counts, [1]);
+        assert!(report.to_text().contains(&usize::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            report("1\n1\n\n4").to_json(),
+            concat!(
+                "{\"elves\":[",
+                "{\"elf\":1,\"items\":2,\"total\":2,\"largest\":1,\"smallest\":1},",
+                "{\"elf\":2,\"items\":1,\"total\":4,\"largest\":4,\"smallest\":4}",
+                "],\"stats\":{\"mean\":3,\"median\":3,\"std_dev\":1},",
+                "\"histogram\":[",
+                "{\"from\":2,\"to\":2,\"elves\":1},",
+                "{\"from\":3,\"to\":3,\"elves\":0},",
+                "{\"from\":4,\"to\":4,\"elves\":1}",
+                "]}"
+            )
+        );
+
+        assert_eq!(
+            report("").to_json(),
+            "{\"elves\":[],\"stats\":null,\"histogram\":[]}"
+        );
+    }
+}
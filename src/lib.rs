@@ -0,0 +1,60 @@
+//! Solutions to the 2022 Advent of Code puzzles, along with the tools the
+//! runner binary uses to fetch inputs, check answers and submit them.
+//!
+//! Each `day_NN` module exports its puzzle's domain types, and every day
+//! implements [`Day`] so it can be parsed and solved on its own. [`DAYS`]
+//! holds every day as a [`Solver`], in order.
+
+#![allow(clippy::upper_case_acronyms)]
+
+pub mod answers;
+pub mod bench;
+pub mod check;
+pub mod cli;
+pub mod client;
+pub mod commands;
+pub mod day;
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod generate;
+pub mod grid;
+pub mod groups_of;
+pub mod input;
+pub mod inventory;
+pub mod parallel;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+mod utils;
+pub mod watch;
+
+pub use crate::{
+    day::{Day, Solver},
+    error::ParseError,
+    solution::{Part, Solution},
+};
+
+/// Every day's solver, where day `n` is at index `n - 1`
+pub const DAYS: [&dyn Solver; 10] = [
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+];
@@ -1,38 +1,118 @@
-use crate::utils::read_input;
+use std::{
+    env,
+    io::{self, IsTerminal, Write},
+    path::Path,
+    process,
+};
 
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod groups_of;
-mod solution;
-mod utils;
+use advent_of_code_2022::{
+    cli::{self, Command},
+    commands::{self, CommandError},
+    DAYS,
+};
 
 fn main() {
-    let days = [
-        day_01::main,
-        day_02::main,
-        day_03::main,
-        day_04::main,
-        day_05::main,
-        day_06::main,
-        day_07::main,
-        day_08::main,
-        day_09::main,
-        day_10::main,
-    ];
+    let command = match Command::parse(env::args().skip(1), DAYS.len()) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            process::exit(2);
+        }
+    };
 
-    for (i, solve_day) in days.into_iter().enumerate() {
-        let day = i + 1;
+    let result = run(command, &mut io::stdout().lock(), &mut io::stderr());
 
-        println!("\n\n-- Day {} --", day);
+    match result {
+        Ok(()) => {}
+        Err(CommandError::Failed) => process::exit(1),
+        Err(CommandError::Error(e)) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(command: Command, out: &mut dyn Write, err: &mut dyn Write) -> Result<(), CommandError> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    match command {
+        Command::Run {
+            format,
+            jobs,
+            example,
+            top: Some(n),
+            inputs,
+            ..
+        } => commands::top(n.get(), format, jobs, example.as_deref(), &inputs, out, err),
+        Command::Run {
+            part,
+            format,
+            example,
+            rules: Some(path),
+            inputs,
+            ..
+        } => commands::play(&path, part, format, example.as_deref(), &inputs, out),
+        Command::Run {
+            days,
+            part,
+            format,
+            jobs,
+            example,
+            top: None,
+            rules: None,
+            inputs,
+        } => commands::run(
+            &days,
+            part,
+            format,
+            jobs,
+            example.as_deref(),
+            &inputs,
+            out,
+            err,
+        ),
+        Command::Bench {
+            iterations,
+            warmup,
+            top: Some(n),
+            jobs,
+            inputs,
+            ..
+        } => commands::bench_top(n.get(), jobs, iterations.get(), warmup, &inputs, out),
+        Command::Bench {
+            days,
+            iterations,
+            warmup,
+            top: None,
+            inputs,
+            ..
+        } => commands::bench(&days, iterations.get(), warmup, &inputs, out),
+        Command::Check { days, inputs } => commands::check(&days, &inputs, out, err),
+        Command::Fetch {
+            days,
+            inputs,
+            base_url,
+            delay,
+        } => commands::fetch(&days, &inputs, &base_url, delay, out),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            inputs,
+            base_url,
+            delay,
+        } => commands::submit(day, part, answer, &inputs, &base_url, delay, out),
+        Command::New { day, inputs } => commands::new(root, day, &inputs, out),
+        Command::Watch { day, inputs } => {
+            let color = io::stdout().is_terminal();
 
-        solve_day(&read_input(day));
+            commands::watch(root, day, &inputs, color, out, err)
+        }
+        Command::Gen { day, size, seed } => commands::gen(day, size, seed, out),
+        Command::Inventory {
+            format,
+            example,
+            inputs,
+        } => commands::inventory(format, example.as_deref(), &inputs, out),
     }
 }
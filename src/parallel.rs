@@ -0,0 +1,103 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Applies `f` to every item on up to `jobs` threads, returning the results in
+/// the same order as `items`. A call to `f` that panics gives an `Err` with the
+/// panic's message, without affecting any of the other items.
+pub fn map_ordered<I, T, F>(items: &[I], jobs: usize, f: F) -> Vec<Result<T, String>>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync,
+{
+    let results: Vec<_> = items.iter().map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+
+    let work = || loop {
+        let i = next.fetch_add(1, Ordering::Relaxed);
+
+        let Some(item) = items.get(i) else {
+            return;
+        };
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(item))).map_err(panic_message);
+
+        *results[i].lock().unwrap() = Some(result);
+    };
+
+    thread::scope(|s| {
+        for _ in 1..jobs.clamp(1, items.len().max(1)) {
+            s.spawn(work);
+        }
+
+        // The current thread takes a share of the work too
+        work();
+    });
+
+    results
+        .into_iter()
+        .map(|r| r.into_inner().unwrap().unwrap())
+        .collect()
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use test_case::test_case;
+
+    #[test_case(1)]
+    #[test_case(3)]
+    #[test_case(20)]
+    fn test_results_are_in_order(jobs: usize) {
+        let items: Vec<u64> = (1..=10).collect();
+
+        let results = map_ordered(&items, jobs, |&n| {
+            // Make the early items finish last
+            thread::sleep(Duration::from_millis(20 - n));
+
+            n * n
+        });
+
+        let expected: Vec<_> = items.iter().map(|n| Ok(n * n)).collect();
+
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_panic_keeps_other_results() {
+        let results = map_ordered(&[1, 2, 3], 2, |&n| {
+            if n == 2 {
+                panic!("day {} exploded", n);
+            }
+
+            n
+        });
+
+        assert_eq!(results, [Ok(1), Err("day 2 exploded".to_owned()), Ok(3)]);
+    }
+
+    #[test]
+    fn test_no_items() {
+        assert_eq!(map_ordered(&[] as &[usize], 4, |&n| n), []);
+    }
+}
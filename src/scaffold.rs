@@ -0,0 +1,212 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::examples::EXAMPLES_DIR;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// The files `new` created or changed
+#[derive(Debug, PartialEq, Eq)]
+pub struct Scaffolded {
+    pub module: PathBuf,
+    pub lib: PathBuf,
+    /// The empty input file, unless there was already an input for the day
+    pub input: Option<PathBuf>,
+    /// The empty example, unless the day already had one
+    pub example: Option<PathBuf>,
+}
+
+/// Creates `src/day_NN.rs` under `root` from the template, registers it in
+/// `src/lib.rs` and creates an empty input file for it in `inputs_dir` and an
+/// empty example. `available` is the number of days already registered.
+pub fn scaffold(
+    root: &Path,
+    inputs_dir: &Path,
+    day: usize,
+    available: usize,
+) -> Result<Scaffolded, ScaffoldError> {
+    let module = root.join("src").join(format!("day_{:02}.rs", day));
+    let lib = root.join("src").join("lib.rs");
+
+    if module.exists() {
+        return Err(ScaffoldError::AlreadyExists(module));
+    }
+
+    if day != available + 1 {
+        return Err(ScaffoldError::NotNextDay {
+            day,
+            next: available + 1,
+        });
+    }
+
+    let lib_rs = fs::read_to_string(&lib).map_err(|e| ScaffoldError::Io(lib.clone(), e))?;
+    let lib_rs = register(&lib_rs, day)?;
+
+    write_new(&module, &render(day)).map_err(|e| ScaffoldError::Io(module.clone(), e))?;
+    fs::write(&lib, lib_rs).map_err(|e| ScaffoldError::Io(lib.clone(), e))?;
+
+    let input = inputs_dir.join(format!("day_{:02}.txt", day));
+    let input = write_if_missing(input, "")?;
+
+    let example = root
+        .join(EXAMPLES_DIR)
+        .join(format!("day_{:02}", day))
+        .join("one.txt");
+
+    // The example tests fail until at least one of these is filled in
+    let answers = format!("# {}.1: ANSWER\n# {}.2: ANSWER\n", day, day);
+
+    write_if_missing(example.with_extension("answers"), &answers)?;
+    let example = write_if_missing(example, "")?;
+
+    Ok(Scaffolded {
+        module,
+        lib,
+        input,
+        example,
+    })
+}
+
+/// Writes a file unless it already exists, returning its path if it was
+/// written
+fn write_if_missing(path: PathBuf, contents: &str) -> Result<Option<PathBuf>, ScaffoldError> {
+    match write_new(&path, contents) {
+        Ok(()) => Ok(Some(path)),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(None),
+        Err(e) => Err(ScaffoldError::Io(path, e)),
+    }
+}
+
+/// Writes a file that mustn't already exist
+fn write_new(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+
+    file.write_all(contents.as_bytes())
+}
+
+fn render(day: usize) -> String {
+    TEMPLATE
+        .replace("{{DAY}}", &format!("{:02}", day))
+        .replace("{{N}}", &day.to_string())
+}
+
+/// Adds `day`'s `mod` declaration and its entry at the end of `DAYS`
+fn register(lib_rs: &str, day: usize) -> Result<String, ScaffoldError> {
+    let previous_mod = format!("pub mod day_{:02};\n", day - 1);
+    let previous_entry = format!("    &day_{:02}::Day{:02},\n", day - 1, day - 1);
+    let days_len = format!("pub const DAYS: [&dyn Solver; {}] = [", day - 1);
+
+    for expected in [&previous_mod, &previous_entry, &days_len] {
+        if !lib_rs.contains(expected.as_str()) {
+            return Err(ScaffoldError::UnexpectedLib(expected.trim().to_owned()));
+        }
+    }
+
+    Ok(lib_rs
+        .replacen(
+            &previous_mod,
+            &format!("{}pub mod day_{:02};\n", previous_mod, day),
+            1,
+        )
+        .replacen(
+            &previous_entry,
+            &format!("{}    &day_{:02}::Day{:02},\n", previous_entry, day, day),
+            1,
+        )
+        .replacen(
+            &days_len,
+            &format!("pub const DAYS: [&dyn Solver; {}] = [", day),
+            1,
+        ))
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    NotNextDay { day: usize, next: usize },
+    UnexpectedLib(String),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => {
+                write!(f, "`{}` already exists, not overwriting it", path.display())
+            }
+            ScaffoldError::NotNextDay { day, next } => {
+                write!(f, "can't add day {}, the next day to add is {}", day, next)
+            }
+            ScaffoldError::UnexpectedLib(expected) => write!(
+                f,
+                "couldn't find `{}` in src/lib.rs to register the new day after",
+                expected
+            ),
+            ScaffoldError::Io(path, e) => write!(f, "couldn't write `{}`: {}", path.display(), e),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_RS: &str = "\
+pub mod day_09;
+pub mod day_10;
+pub mod error;
+
+pub const DAYS: [&dyn Solver; 10] = [
+    &day_09::Day09,
+    &day_10::Day10,
+];
+";
+
+    #[test]
+    fn test_register() {
+        similar_asserts::assert_eq!(
+            register(LIB_RS, 11).unwrap(),
+            "\
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod error;
+
+pub const DAYS: [&dyn Solver; 11] = [
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+];
+"
+        );
+    }
+
+    #[test]
+    fn test_register_unexpected_lib() {
+        assert!(matches!(
+            register(LIB_RS, 12),
+            Err(ScaffoldError::UnexpectedLib(expected)) if expected == "pub mod day_11;"
+        ));
+    }
+
+    #[test]
+    fn test_render() {
+        let module = render(11);
+
+        assert!(module.contains("pub struct Day11;"));
+        assert!(module.contains("const DAY: usize = 11;"));
+        assert!(module.contains("read_input(11)"));
+        assert!(!module.contains("{{"));
+    }
+}
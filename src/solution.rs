@@ -1,17 +1,222 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr, time::Duration};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// How long each phase of solving a day took. A part that wasn't run has no
+/// timing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one.unwrap_or_default() + self.part_two.unwrap_or_default()
+    }
+}
+
+pub const CSV_HEADER: &str = "day,part_one,part_two,parse_ns,part_one_ns,part_two_ns";
+
+#[derive(Debug)]
 pub struct Solution<A, B> {
     pub part_one: A,
     pub part_two: B,
 }
 
-impl<A, B> Solution<A, B>
+impl<A, B> Solution<Option<A>, Option<B>>
 where
     A: Display,
     B: Display,
 {
-    pub fn print(&self) {
-        println!("Part one: {}", self.part_one);
-        println!("Part two: {}", self.part_two);
+    /// A line for each answer, leaving out the parts that weren't run
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        if let Some(a) = &self.part_one {
+            text += &format!("Part one: {}\n", a);
+        }
+
+        if let Some(b) = &self.part_two {
+            text += &format!("Part two: {}\n", b);
+        }
+
+        text
+    }
+
+    /// A JSON object with the day, both answers (as strings, or `null` if the
+    /// part wasn't run) and the timings in nanoseconds if there are any
+    pub fn to_json(&self, day: usize, timings: Option<&Timings>) -> String {
+        let mut json = format!(
+            "{{\"day\":{},\"part_one\":{},\"part_two\":{}",
+            day,
+            json_string(self.part_one.as_ref()),
+            json_string(self.part_two.as_ref())
+        );
+
+        if let Some(t) = timings {
+            json += &format!(
+                ",\"timings\":{{\"parse_ns\":{},\"part_one_ns\":{},\"part_two_ns\":{}}}",
+                t.parse.as_nanos(),
+                json_nanos(t.part_one),
+                json_nanos(t.part_two)
+            );
+        }
+
+        json.push('}');
+
+        json
+    }
+
+    /// A row to go under [`CSV_HEADER`], leaving out anything that
+    /// wasn't run or measured
+    pub fn to_csv(&self, day: usize, timings: Option<&Timings>) -> String {
+        let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+
+        [
+            day.to_string(),
+            csv_field(self.part_one.as_ref()),
+            csv_field(self.part_two.as_ref()),
+            nanos(timings.map(|t| t.parse)),
+            nanos(timings.and_then(|t| t.part_one)),
+            nanos(timings.and_then(|t| t.part_two)),
+        ]
+        .join(",")
+    }
+}
+
+fn json_string<T: Display>(value: Option<&T>) -> String {
+    let Some(value) = value else {
+        return "null".to_owned();
+    };
+
+    let mut json = String::from('"');
+
+    for c in value.to_string().chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+
+    json
+}
+
+fn json_nanos(d: Option<Duration>) -> String {
+    d.map(|d| d.as_nanos().to_string())
+        .unwrap_or_else(|| "null".to_owned())
+}
+
+/// Quotes a field if it holds anything that would break the row apart
+fn csv_field<T: Display>(value: Option<&T>) -> String {
+    let value = value.map(T::to_string).unwrap_or_default();
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// How the runner prints each day's solution
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crt_solution() -> Solution<Option<isize>, Option<String>> {
+        Solution {
+            part_one: Some(13140),
+            part_two: Some("\n##..\n#\"#,\n".to_owned()),
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let timings = Timings {
+            parse: Duration::from_nanos(1500),
+            part_one: Some(Duration::from_nanos(20)),
+            part_two: None,
+        };
+
+        assert_eq!(
+            crt_solution().to_json(10, Some(&timings)),
+            r##"{"day":10,"part_one":"13140","part_two":"\n##..\n#\"#,\n","timings":{"parse_ns":1500,"part_one_ns":20,"part_two_ns":null}}"##
+        );
+
+        let skipped: Solution<Option<usize>, Option<usize>> = Solution {
+            part_one: None,
+            part_two: Some(2653),
+        };
+
+        assert_eq!(
+            skipped.to_json(9, None),
+            r#"{"day":9,"part_one":null,"part_two":"2653"}"#
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            crt_solution().to_csv(10, None),
+            "10,13140,\"\n##..\n#\"\"#,\n\",,,"
+        );
     }
 }
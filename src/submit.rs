@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use crate::{
+    answers::Verdict,
+    client::{Client, ClientError},
+    solution::Part,
+};
+
+/// What the site said in response to a submitted answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently, try again after this long
+    Wait(Duration),
+    /// The part has already been solved, or the part before it hasn't been
+    AlreadySolved,
+}
+
+impl Response {
+    /// Picks out the response from the page the site sends back
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Response::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Response::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Response::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Response::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Some(Response::Wait(
+                parse_wait(page).unwrap_or(Duration::from_secs(60)),
+            ))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Response::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// How the answer should be recorded, if the response says anything
+    /// about it
+    pub fn verdict(&self) -> Option<Verdict> {
+        match self {
+            Response::Correct => Some(Verdict::Correct),
+            Response::TooHigh => Some(Verdict::TooHigh),
+            Response::TooLow => Some(Verdict::TooLow),
+            Response::Wrong => Some(Verdict::Wrong),
+            Response::Wait(_) | Response::AlreadySolved => None,
+        }
+    }
+}
+
+/// The wait in a message like "You have 1m 5s left to wait"
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .try_fold(Duration::ZERO, |total, unit| {
+            let (n, secs_per) = if let Some(n) = unit.strip_suffix('h') {
+                (n, 3600)
+            } else if let Some(n) = unit.strip_suffix('m') {
+                (n, 60)
+            } else {
+                (unit.strip_suffix('s')?, 1)
+            };
+
+            Some(total + Duration::from_secs(n.parse::<u64>().ok()? * secs_per))
+        })
+}
+
+pub fn submit(
+    client: &Client,
+    day: usize,
+    part: Part,
+    answer: &str,
+) -> Result<Response, ClientError> {
+    let path = format!("day/{}/answer", day);
+    let level = part.to_string();
+
+    let page = client.post(&path, &[("level", &level), ("answer", answer)])?;
+
+    Response::parse(&page).ok_or_else(|| {
+        ClientError::Http(
+            path,
+            "the response didn't say whether the answer was right".into(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_case::test_case;
+
+    #[test_case(
+        "<p>That's the right answer!  You are one gold star closer.</p>",
+        Response::Correct
+    )]
+    #[test_case(
+        "<p>That's not the right answer; your answer is too high.</p>",
+        Response::TooHigh
+    )]
+    #[test_case(
+        "<p>That's not the right answer; your answer is too low.</p>",
+        Response::TooLow
+    )]
+    #[test_case(
+        "<p>That's not the right answer.  If you're stuck...</p>",
+        Response::Wrong
+    )]
+    #[test_case("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.</p>", Response::Wait(Duration::from_secs(34)))]
+    #[test_case(
+        "<p>You gave an answer too recently.  You have 1m 5s left to wait.</p>",
+        Response::Wait(Duration::from_secs(65))
+    )]
+    #[test_case(
+        "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+        Response::AlreadySolved
+    )]
+    fn test_parse_response(page: &str, expected: Response) {
+        assert_eq!(Response::parse(page), Some(expected));
+    }
+
+    #[test]
+    fn test_parse_unknown_response() {
+        assert_eq!(Response::parse("<p>Something else entirely</p>"), None);
+    }
+}
@@ -1,12 +1,21 @@
-use std::{fmt::Debug, fs::read_to_string, str::FromStr};
+use std::str::FromStr;
 
+use crate::error::ParseError;
+
+/// The input for `day` from the default inputs directory, for tests
+#[cfg(test)]
 pub fn read_input(day: usize) -> String {
-    read_to_string(format!("inputs/day_{:02}.txt", day)).unwrap()
+    crate::input::Inputs::default().read(day).unwrap()
 }
 
-pub fn parse_as<F: FromStr>(s: &str) -> F
-where
-    <F as FromStr>::Err: Debug,
-{
-    s.parse::<F>().unwrap()
+/// Parses `token`, a slice of `line`, reporting what was `expected` if it
+/// isn't valid
+pub fn parse_as<F: FromStr>(
+    line: &str,
+    token: &str,
+    expected: &'static str,
+) -> Result<F, ParseError> {
+    token
+        .parse::<F>()
+        .map_err(|_| ParseError::new(line, token, expected))
 }
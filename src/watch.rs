@@ -0,0 +1,223 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answers::{format_line, KnownAnswers, Verdict},
+    day::Answers,
+    solution::Part,
+};
+
+/// How often the watched files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Environment variable used to hand the last answers on to the rebuilt
+/// binary, so it can show them beside its own
+pub const PREVIOUS_VAR: &str = "AOC_WATCH_PREVIOUS";
+
+/// Notices files changing by polling their modification times
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: &[&Path]) -> Self {
+        Watcher {
+            files: paths.iter().map(|&p| (p.to_owned(), modified(p))).collect(),
+        }
+    }
+
+    /// The files that have changed (or appeared or disappeared) since the
+    /// last poll
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+
+        for (path, last_modified) in &mut self.files {
+            let modified = modified(path);
+
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed.push(path.clone());
+            }
+        }
+
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Encodes `day`'s answers in the answers file format for [`PREVIOUS_VAR`]
+pub fn encode_previous(day: usize, answers: &Answers) -> String {
+    let parts = [
+        (Part::One, &answers.part_one),
+        (Part::Two, &answers.part_two),
+    ];
+
+    parts
+        .into_iter()
+        .filter_map(|(part, answer)| {
+            let answer = answer.as_deref()?;
+
+            Some(format_line(day, part, answer, Verdict::Correct) + "\n")
+        })
+        .collect()
+}
+
+/// Decodes the answers encoded by [`encode_previous`], ignoring anything
+/// that can't be read
+pub fn decode_previous(day: usize, encoded: &str) -> Answers {
+    let known = KnownAnswers::parse(encoded).unwrap_or_default();
+
+    Answers {
+        part_one: known.get(day, Part::One).map(str::to_owned),
+        part_two: known.get(day, Part::Two).map(str::to_owned),
+    }
+}
+
+/// A line (or lines) showing `current`, and how it compares to the
+/// `previous` answer if there was one. Changes are highlighted when `color`
+/// is set.
+pub fn render_part(name: &str, current: &str, previous: Option<&str>, color: bool) -> String {
+    let (highlight, dim, reset) = if color {
+        ("\x1b[1;33m", "\x1b[2m", "\x1b[0m")
+    } else {
+        ("", "", "")
+    };
+
+    match previous {
+        None => format!("{}: {}", name, current),
+        Some(previous) if previous == current => {
+            format!("{}: {} {}(unchanged){}", name, current, dim, reset)
+        }
+        Some(previous) if current.contains('\n') || previous.contains('\n') => format!(
+            "{}{}: {} (changed){}\n{}was: {}{}",
+            highlight, name, current, reset, dim, previous, reset
+        ),
+        Some(previous) => format!(
+            "{}{}: {} (was {}){}",
+            highlight, name, current, previous, reset
+        ),
+    }
+}
+
+/// The lines showing each of the parts in `answers`, beside the `previous`
+/// answers if there were any
+pub fn render_changes(answers: &Answers, previous: Option<&Answers>, color: bool) -> String {
+    let parts = [
+        ("Part one", &answers.part_one, previous.map(|p| &p.part_one)),
+        ("Part two", &answers.part_two, previous.map(|p| &p.part_two)),
+    ];
+
+    parts
+        .into_iter()
+        .filter_map(|(name, current, previous)| {
+            let current = current.as_deref()?;
+            let previous = previous.and_then(|p| p.as_deref());
+
+            Some(render_part(name, current, previous, color) + "\n")
+        })
+        .collect()
+}
+
+/// Builds the `bin` binary in `root` with the same profile as this one,
+/// returning whether it succeeded
+pub fn rebuild(root: &Path, bin: &str) -> bool {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+
+    let mut command = process::Command::new(cargo);
+
+    command.current_dir(root).args(["build", "--bin", bin]);
+
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    command.status().is_ok_and(|status| status.success())
+}
+
+/// Replaces this process with `command`, returning only if it couldn't be run
+#[cfg(unix)]
+pub fn reexec(mut command: process::Command) -> io::Error {
+    use std::os::unix::process::CommandExt;
+
+    command.exec()
+}
+
+/// Runs `command` in place of this process, passing on its exit code, and
+/// returning only if it couldn't be run
+#[cfg(not(unix))]
+pub fn reexec(mut command: process::Command) -> io::Error {
+    match command.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs::File;
+
+    use test_case::test_case;
+
+    #[test]
+    fn test_poll() {
+        let path = env::temp_dir().join(format!("aoc-2022-watch-{}.txt", std::process::id()));
+
+        fs::write(&path, "1").unwrap();
+
+        let mut watcher = Watcher::new(&[&path]);
+
+        assert!(watcher.poll().is_empty());
+
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+
+        assert_eq!(watcher.poll().len(), 1);
+        assert!(watcher.poll().is_empty());
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(watcher.poll(), [path]);
+    }
+
+    #[test]
+    fn test_previous_round_trips() {
+        let answers = Answers {
+            part_one: Some("13140".to_owned()),
+            part_two: Some("\n##..\n##..\n".to_owned()),
+        };
+
+        let decoded = decode_previous(10, &encode_previous(10, &answers));
+
+        assert_eq!(decoded.part_one, answers.part_one);
+        assert_eq!(decoded.part_two, answers.part_two);
+    }
+
+    #[test_case("1198", None, "Part one: 1198")]
+    #[test_case("1198", Some("1198"), "Part one: 1198 (unchanged)")]
+    #[test_case("1198", Some("1197"), "Part one: 1198 (was 1197)")]
+    #[test_case("\n#.\n", Some("\n.#\n"), "Part one: \n#.\n (changed)\nwas: \n.#\n")]
+    fn test_render_part(current: &str, previous: Option<&str>, expected: &str) {
+        assert_eq!(render_part("Part one", current, previous, false), expected);
+    }
+
+    #[test]
+    fn test_render_part_highlights_changes() {
+        assert_eq!(
+            render_part("Part two", "3120", Some("3119"), true),
+            "\x1b[1;33mPart two: 3120 (was 3119)\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{day::Day, error::ParseError};
+
+pub struct Day{{DAY}};
+
+impl Day for Day{{DAY}} {
+    const DAY: usize = {{N}};
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_one(lines: &Self::Parsed<'_>) -> Self::PartOne {
+        lines.len()
+    }
+
+    fn part_two(lines: &Self::Parsed<'_>) -> Self::PartTwo {
+        lines.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::known_answer, solution::Part, utils::read_input};
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(
+            Day{{DAY}}::solution(&read_input({{N}}))
+                .unwrap()
+                .part_one
+                .to_string(),
+            known_answer({{N}}, Part::One)
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            Day{{DAY}}::solution(&read_input({{N}}))
+                .unwrap()
+                .part_two
+                .to_string(),
+            known_answer({{N}}, Part::Two)
+        );
+    }
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use std::fs;
+
+use common::{run, stderr, stdout, temp_dir};
+
+#[test]
+fn test_carries_on_past_a_day_that_fails() {
+    let dir = temp_dir("check-carries-on");
+
+    fs::write(dir.join("day_01.txt"), "1000\n\n2000\n").unwrap();
+    fs::write(dir.join("day_03.txt"), "not a rucksack!\n").unwrap();
+    fs::write(dir.join("day_04.txt"), "2-4,6-8\n").unwrap();
+    fs::write(dir.join("answers.txt"), "1.1: 2000\n4.1: 0\n").unwrap();
+
+    let output = run(&["check", "1..=4", "--inputs", dir.to_str().unwrap()]);
+    let out = stdout(&output);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(out.contains("Day  1 part 1: pass"), "{}", out);
+    assert!(out.contains("Day  4 part 1: pass"), "{}", out);
+    assert!(
+        out.ends_with("\n2 passed, 4 failed, 2 unknown\n"),
+        "{}",
+        out
+    );
+
+    let err = stderr(&output);
+
+    assert!(err.contains("no input for day 2"), "{}", err);
+    assert!(err.contains("day 3"), "{}", err);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_names_a_malformed_answers_file() {
+    let dir = temp_dir("check-malformed-answers");
+    let answers = dir.join("answers.txt");
+
+    fs::write(dir.join("day_01.txt"), "1000\n").unwrap();
+    fs::write(&answers, "1.1: 1000\n1.2 = 1000\n").unwrap();
+
+    let output = run(&["check", "1", "--inputs", dir.to_str().unwrap()]);
+    let err = stderr(&output);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        err.contains(&format!("invalid answers in `{}`", answers.display())),
+        "{}",
+        err
+    );
+    assert!(err.contains("line 2"), "{}", err);
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,367 @@
+//! Naive solutions to each day, written to be obviously correct rather than
+//! fast, for checking the real solutions against. They expect valid input
+//! and panic on anything else.
+
+use std::collections::{HashMap, HashSet};
+
+use advent_of_code_2022::Solution;
+
+/// A reference solution, giving both answers as the real solution formats
+/// them
+pub type Reference = fn(&str) -> Solution<String, String>;
+
+/// Every day's reference solution, where day `n` is at index `n - 1`
+pub const REFERENCES: [Reference; 10] = [
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10,
+];
+
+fn solution(part_one: impl ToString, part_two: impl ToString) -> Solution<String, String> {
+    Solution {
+        part_one: part_one.to_string(),
+        part_two: part_two.to_string(),
+    }
+}
+
+/// Sorts every elf's total and takes the biggest ones
+pub fn day_01(input: &str) -> Solution<String, String> {
+    let mut totals: Vec<usize> = input
+        .trim_end()
+        .split("\n\n")
+        .filter(|elf| !elf.is_empty())
+        .map(|elf| elf.lines().map(|l| l.parse::<usize>().unwrap()).sum())
+        .collect();
+
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+
+    solution(
+        totals.first().copied().unwrap_or(0),
+        totals.iter().take(3).sum::<usize>(),
+    )
+}
+
+/// Plays out every round, trying each shape to find the one that gives the
+/// wanted result for part two
+pub fn day_02(input: &str) -> Solution<String, String> {
+    // Rock, paper and scissors are 0, 1 and 2, and each beats the one before
+    let beats = |a: usize, b: usize| a == (b + 1) % 3;
+
+    let outcome = |opponent: usize, you: usize| {
+        if beats(you, opponent) {
+            6
+        } else if beats(opponent, you) {
+            0
+        } else {
+            3
+        }
+    };
+
+    let (mut part_one, mut part_two) = (0, 0);
+
+    for line in input.lines() {
+        let bytes = line.as_bytes();
+        let opponent = (bytes[0] - b'A') as usize;
+        let column = (bytes[2] - b'X') as usize;
+
+        part_one += column + 1 + outcome(opponent, column);
+
+        let wanted = [0, 3, 6][column];
+        let you = (0..3)
+            .find(|&you| outcome(opponent, you) == wanted)
+            .unwrap();
+
+        part_two += you + 1 + wanted;
+    }
+
+    solution(part_one, part_two)
+}
+
+fn priority(item: char) -> usize {
+    ('a'..='z')
+        .chain('A'..='Z')
+        .position(|c| c == item)
+        .unwrap()
+        + 1
+}
+
+/// Searches for the common items by checking every item against the others
+pub fn day_03(input: &str) -> Solution<String, String> {
+    let rucksacks: Vec<&str> = input.lines().collect();
+
+    let part_one: usize = rucksacks
+        .iter()
+        .map(|r| {
+            let (a, b) = r.split_at(r.len() / 2);
+
+            priority(a.chars().find(|&c| b.contains(c)).unwrap())
+        })
+        .sum();
+
+    let part_two: usize = rucksacks
+        .chunks(3)
+        .map(|group| {
+            let badge = group[0]
+                .chars()
+                .find(|&c| group[1].contains(c) && group[2].contains(c))
+                .unwrap();
+
+            priority(badge)
+        })
+        .sum();
+
+    solution(part_one, part_two)
+}
+
+/// Compares the sets of sections each elf cleans
+pub fn day_04(input: &str) -> Solution<String, String> {
+    let sections = |range: &str| -> HashSet<usize> {
+        let (start, end) = range.split_once('-').unwrap();
+
+        (start.parse().unwrap()..=end.parse().unwrap()).collect()
+    };
+
+    let (mut part_one, mut part_two) = (0, 0);
+
+    for line in input.lines() {
+        let (a, b) = line.split_once(',').unwrap();
+        let (a, b) = (sections(a), sections(b));
+
+        if a.is_subset(&b) || b.is_subset(&a) {
+            part_one += 1;
+        }
+
+        if !a.is_disjoint(&b) {
+            part_two += 1;
+        }
+    }
+
+    solution(part_one, part_two)
+}
+
+/// Moves crates between `Vec`s, then reads the crate on top of every stack
+/// that has any
+pub fn day_05(input: &str) -> Solution<String, String> {
+    let (diagram, moves) = input.split_once("\n\n").unwrap();
+    let mut rows: Vec<&str> = diagram.lines().collect();
+    let numbers = rows.pop().unwrap();
+
+    let mut stacks = vec![Vec::new(); numbers.split_whitespace().count()];
+
+    for row in rows.iter().rev() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.chars().nth(1 + 4 * i) {
+                Some(c) if c != ' ' => stack.push(c),
+                _ => {}
+            }
+        }
+    }
+
+    let moves: Vec<(usize, usize, usize)> = moves
+        .lines()
+        .map(|l| {
+            let words: Vec<&str> = l.split(' ').collect();
+
+            (
+                words[1].parse().unwrap(),
+                words[3].parse::<usize>().unwrap() - 1,
+                words[5].parse::<usize>().unwrap() - 1,
+            )
+        })
+        .collect();
+
+    let tops =
+        |stacks: &[Vec<char>]| -> String { stacks.iter().filter_map(|s| s.last()).collect() };
+
+    let mut one_at_a_time = stacks.clone();
+
+    for &(num, from, to) in &moves {
+        for _ in 0..num {
+            let c = one_at_a_time[from].pop().unwrap();
+            one_at_a_time[to].push(c);
+        }
+    }
+
+    let mut all_at_once = stacks;
+
+    for &(num, from, to) in &moves {
+        let len = all_at_once[from].len();
+        let moved = all_at_once[from].split_off(len - num);
+        all_at_once[to].extend(moved);
+    }
+
+    solution(tops(&one_at_a_time), tops(&all_at_once))
+}
+
+/// Checks every window in turn
+pub fn day_06(input: &str) -> Solution<String, String> {
+    let chars: Vec<char> = input.trim_end().chars().collect();
+
+    let marker_end = |len: usize| {
+        (len..=chars.len())
+            .find(|&end| chars[end - len..end].iter().collect::<HashSet<_>>().len() == len)
+            .unwrap()
+    };
+
+    solution(marker_end(4), marker_end(14))
+}
+
+/// Records every file by its full path, then adds up the files under each
+/// directory that was visited
+pub fn day_07(input: &str) -> Solution<String, String> {
+    let mut cwd: Vec<&str> = Vec::new();
+    let mut dirs: HashSet<Vec<&str>> = HashSet::new();
+    let mut files: HashMap<Vec<&str>, usize> = HashMap::new();
+
+    for line in input.lines() {
+        if let Some(dir) = line.strip_prefix("$ cd ") {
+            match dir {
+                "/" => cwd.clear(),
+                ".." => {
+                    cwd.pop();
+                }
+                _ => cwd.push(dir),
+            }
+
+            dirs.insert(cwd.clone());
+        } else if line != "$ ls" && !line.starts_with("dir ") {
+            let (size, name) = line.split_once(' ').unwrap();
+
+            let mut path = cwd.clone();
+            path.push(name);
+
+            files.insert(path, size.parse().unwrap());
+        }
+    }
+
+    let sizes: Vec<usize> = dirs
+        .iter()
+        .map(|dir| {
+            files
+                .iter()
+                .filter(|(path, _)| path.starts_with(dir))
+                .map(|(_, size)| size)
+                .sum()
+        })
+        .collect();
+
+    let used: usize = files.values().sum();
+    let needed = (used + 30_000_000).saturating_sub(70_000_000);
+
+    solution(
+        sizes.iter().filter(|&&s| s <= 100_000).sum::<usize>(),
+        sizes.iter().filter(|&&s| s >= needed).min().unwrap(),
+    )
+}
+
+/// Walks out from every tree in each direction
+pub fn day_08(input: &str) -> Solution<String, String> {
+    let trees: Vec<Vec<u32>> = input
+        .lines()
+        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect();
+
+    let (mut visible, mut best_score) = (0, 0);
+
+    for (y, row) in trees.iter().enumerate() {
+        for (x, &height) in row.iter().enumerate() {
+            let lines_of_sight: [Vec<u32>; 4] = [
+                row[..x].iter().rev().copied().collect(),
+                row[x + 1..].to_vec(),
+                trees[..y].iter().rev().map(|r| r[x]).collect(),
+                trees[y + 1..].iter().map(|r| r[x]).collect(),
+            ];
+
+            if lines_of_sight
+                .iter()
+                .any(|line| line.iter().all(|&h| h < height))
+            {
+                visible += 1;
+            }
+
+            let score: usize = lines_of_sight
+                .iter()
+                .map(|line| match line.iter().position(|&h| h >= height) {
+                    Some(i) => i + 1,
+                    None => line.len(),
+                })
+                .product();
+
+            best_score = best_score.max(score);
+        }
+    }
+
+    solution(visible, best_score)
+}
+
+/// Moves every knot of the rope one step at a time
+pub fn day_09(input: &str) -> Solution<String, String> {
+    let visited = |knots: usize| {
+        let mut rope = vec![(0i64, 0i64); knots];
+        let mut visited = HashSet::from([(0, 0)]);
+
+        for line in input.lines() {
+            let (dir, steps) = line.split_once(' ').unwrap();
+
+            let (dx, dy) = match dir {
+                "U" => (0, 1),
+                "D" => (0, -1),
+                "L" => (-1, 0),
+                _ => (1, 0),
+            };
+
+            for _ in 0..steps.parse::<usize>().unwrap() {
+                rope[0].0 += dx;
+                rope[0].1 += dy;
+
+                for i in 1..knots {
+                    let (x, y) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+
+                    if x.abs() > 1 || y.abs() > 1 {
+                        rope[i].0 += x.signum();
+                        rope[i].1 += y.signum();
+                    }
+                }
+
+                visited.insert(rope[knots - 1]);
+            }
+        }
+
+        visited.len()
+    };
+
+    solution(visited(2), visited(10))
+}
+
+/// Lists the register's value during every cycle, then reads the answers off
+/// that
+pub fn day_10(input: &str) -> Solution<String, String> {
+    let mut x = 1;
+    let mut during = Vec::new();
+
+    for line in input.lines() {
+        during.push(x);
+
+        if let Some(v) = line.strip_prefix("addx ") {
+            during.push(x);
+            x += v.parse::<i64>().unwrap();
+        }
+    }
+
+    let signal_strength: i64 = (20..=during.len())
+        .step_by(40)
+        .map(|cycle| cycle as i64 * during[cycle - 1])
+        .sum();
+
+    let mut screen = String::from("\n");
+
+    for row in 0..6 {
+        for col in 0..40 {
+            let lit = matches!(during.get(row * 40 + col), Some(x) if (x - col as i64).abs() <= 1);
+
+            screen.push(if lit { '#' } else { '.' });
+        }
+
+        screen.push('\n');
+    }
+
+    solution(signal_strength, screen)
+}
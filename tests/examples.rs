@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use advent_of_code_2022::{
+    examples::{self, Example, EXAMPLES_DIR},
+    DAYS,
+};
+
+/// Solves every example under `examples/`, so adding a case is a matter of
+/// adding its files. Every example needs an answer for at least one part.
+#[test]
+fn test_every_example() {
+    let dir = Path::new(EXAMPLES_DIR);
+
+    let mut failures = Vec::new();
+
+    for day in 1..=DAYS.len() {
+        let names = examples::names(dir, day).unwrap();
+
+        if names.is_empty() {
+            failures.push(format!("day {} has no examples", day));
+        }
+
+        for name in names {
+            let example = Example::load(dir, day, &name).unwrap();
+
+            // A new day's example starts with only commented out placeholders
+            if example.expected.part_one.is_none() && example.expected.part_two.is_none() {
+                failures.push(format!(
+                    "day {} example `{}` has no answers in `{}.answers`",
+                    day, name, name
+                ));
+            }
+
+            let answers = match DAYS[day - 1].solve(&example.input, None) {
+                Ok((answers, _)) => answers,
+                Err(e) => {
+                    failures.push(format!("day {} example `{}`: {}", day, name, e));
+                    continue;
+                }
+            };
+
+            for (part, expected, actual) in example.mismatches(&answers) {
+                failures.push(format!(
+                    "day {} example `{}` part {}: expected {:?}, got {:?}",
+                    day, name, part, expected, actual
+                ));
+            }
+        }
+    }
+
+    similar_asserts::assert_eq!(failures, Vec::<String>::new());
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use std::{fs, time::Duration};
+
+use common::{run, run_with_env, stderr, stdout, temp_dir, MockServer};
+
+fn fetch(server: &MockServer, dir: &std::path::Path, days: &str) -> std::process::Output {
+    run(&[
+        "fetch",
+        days,
+        "--inputs",
+        dir.to_str().unwrap(),
+        "--base-url",
+        &server.url,
+        "--delay",
+        "0",
+    ])
+}
+
+#[test]
+fn test_downloads_input_with_session_cookie() {
+    let server = MockServer::start(|_| (200, "1000\n2000\n".to_owned()));
+    let dir = temp_dir("fetch-download");
+
+    fs::write(dir.join(".session"), "abc123\n").unwrap();
+
+    let output = fetch(&server, &dir, "1");
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        fs::read_to_string(dir.join("day_01.txt")).unwrap(),
+        "1000\n2000\n"
+    );
+
+    let requests = server.requests();
+
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+}
+
+#[test]
+fn test_fetches_into_a_new_set() {
+    let server = MockServer::start(|_| (200, "1000\n".to_owned()));
+    let dir = temp_dir("fetch-new-set");
+
+    let output = run_with_env(
+        &[
+            "fetch",
+            "1",
+            "--inputs",
+            dir.to_str().unwrap(),
+            "--set",
+            "alice",
+            "--base-url",
+            &server.url,
+            "--delay",
+            "0",
+        ],
+        &[("AOC_SESSION", "abc123")],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        fs::read_to_string(dir.join("alice").join("day_01.txt")).unwrap(),
+        "1000\n"
+    );
+    assert!(dir.join("alice").join(".last-request").exists());
+}
+
+#[test]
+fn test_never_refetches_cached_input() {
+    let server = MockServer::start(|_| (200, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".to_owned()));
+    let dir = temp_dir("fetch-cached");
+
+    fs::write(dir.join(".session"), "abc123").unwrap();
+    fs::write(dir.join("day_06.txt"), "bvwbjplbgvbhsrlpgdmjqwftvncz\n").unwrap();
+
+    let output = fetch(&server, &dir, "6");
+
+    assert!(stdout(&output).contains("already have"));
+    assert_eq!(
+        fs::read_to_string(dir.join("day_06.txt")).unwrap(),
+        "bvwbjplbgvbhsrlpgdmjqwftvncz\n"
+    );
+    assert!(server.requests().is_empty());
+
+    fetch(&server, &dir, "7");
+    fetch(&server, &dir, "6..=7");
+
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_not_unlocked() {
+    let server = MockServer::start(|_| {
+        (
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!".to_owned(),
+        )
+    });
+    let dir = temp_dir("fetch-locked");
+
+    fs::write(dir.join(".session"), "abc123").unwrap();
+
+    let output = fetch(&server, &dir, "3");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("day 3 isn't unlocked yet"));
+    assert!(!dir.join("day_03.txt").exists());
+}
+
+#[test]
+fn test_rejected_session() {
+    let server = MockServer::start(|_| (400, "Please log in".to_owned()));
+    let dir = temp_dir("fetch-bad-session");
+
+    fs::write(dir.join(".session"), "expired").unwrap();
+
+    let output = fetch(&server, &dir, "2");
+
+    assert!(stderr(&output).contains("session token was rejected"));
+    assert!(!dir.join("day_02.txt").exists());
+}
+
+#[test]
+fn test_missing_session() {
+    let server = MockServer::start(|_| (200, String::new()));
+    let dir = temp_dir("fetch-no-session");
+
+    let output = fetch(&server, &dir, "2");
+
+    assert!(stderr(&output).contains("no session token"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_throttles_requests() {
+    let server = MockServer::start(|_| (200, "input\n".to_owned()));
+    let dir = temp_dir("fetch-throttle");
+
+    fs::write(dir.join(".session"), "abc123").unwrap();
+
+    let output = run(&[
+        "fetch",
+        "1..=2",
+        "--inputs",
+        dir.to_str().unwrap(),
+        "--base-url",
+        &server.url,
+        "--delay",
+        "0.3",
+    ]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let requests = server.requests();
+
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].received - requests[0].received >= Duration::from_millis(250));
+}
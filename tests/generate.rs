@@ -0,0 +1,82 @@
+mod common;
+
+use advent_of_code_2022::{
+    day_01::{self, Day01},
+    generate::{generate, GENERATORS},
+    Day, DAYS,
+};
+use common::reference::REFERENCES;
+
+fn generated(day: usize, size: usize, seed: u64) -> String {
+    let mut out = Vec::new();
+
+    generate(day, size, seed, &mut out).unwrap();
+
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_generated_inputs_agree_with_the_references() {
+    let mut failures = Vec::new();
+
+    for day in 1..=GENERATORS.len() {
+        // A grid's size is its width, so keep it small
+        let sizes: &[usize] = if day == 8 { &[0, 1, 30] } else { &[0, 1, 300] };
+
+        for &size in sizes {
+            for seed in 0..5 {
+                let input = generated(day, size, seed);
+
+                let answers = match DAYS[day - 1].solve(&input, None) {
+                    Ok((answers, _)) => (answers.part_one.unwrap(), answers.part_two.unwrap()),
+                    Err(e) => {
+                        failures.push(format!("day {} size {} seed {}: {}", day, size, seed, e));
+                        continue;
+                    }
+                };
+
+                let reference = REFERENCES[day - 1](&input);
+
+                if answers != (reference.part_one, reference.part_two) {
+                    failures.push(format!(
+                        "day {} size {} seed {}: different answers",
+                        day, size, seed
+                    ));
+                }
+            }
+        }
+    }
+
+    similar_asserts::assert_eq!(failures, Vec::<String>::new());
+}
+
+#[test]
+fn test_large_inputs_parse() {
+    for day in 1..=GENERATORS.len() {
+        let size = if day == 8 { 500 } else { 50_000 };
+
+        let input = generated(day, size, 1);
+
+        if let Err(e) = DAYS[day - 1].solve(&input, None) {
+            panic!("day {} with size {}: {}", day, size, e);
+        }
+    }
+}
+
+#[test]
+fn test_every_way_of_ranking_day_01_agrees() {
+    for n in [1, 3, 10, 25] {
+        let input = generated(1, 20_000, n as u64);
+
+        let expected = day_01::top_n(Day01::parse(&input).unwrap(), n);
+
+        assert_eq!(
+            day_01::top_n_from_reader(input.as_bytes(), n).unwrap(),
+            expected
+        );
+
+        for jobs in [1, 2, 7] {
+            assert_eq!(day_01::top_n_parallel(&input, n, jobs).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use advent_of_code_2022::{
+    commands::{self, CommandError},
+    day_05::{Day05, Instruction, Stacks},
+    day_07::all_dir_sizes,
+    day_10::{run_program, Instr, CPU, CRT},
+    input::Inputs,
+    solution::Format,
+    Day, Part, Solution, DAYS,
+};
+
+#[test]
+fn test_stacks() {
+    let mut stacks = Stacks::default();
+
+    stacks.0[0].add_to_top('Z');
+    stacks.0[0].add_to_top('N');
+    stacks.0[1].add_to_top('M');
+
+    assert_eq!(stacks.to_string(), "NM");
+
+    let instruction: Instruction = "move 2 from 1 to 2".parse().unwrap();
+
+    let mut one_at_a_time = stacks;
+    one_at_a_time.update_v1(instruction);
+
+    assert_eq!(one_at_a_time.0[1].crates(), ['M', 'N', 'Z']);
+
+    let mut all_at_once = stacks;
+    all_at_once.update_v2(instruction);
+
+    assert_eq!(all_at_once.0[1].crates(), ['M', 'Z', 'N']);
+}
+
+#[test]
+fn test_day_05_parse() {
+    let (stacks, instructions) =
+        Day05::parse("[A]    \n[B] [C]\n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+
+    assert_eq!(stacks.0[0].crates(), ['B', 'A']);
+    assert_eq!(instructions.len(), 1);
+    assert_eq!(Day05::part_one(&(stacks, instructions)).to_string(), "BA");
+}
+
+#[test]
+fn test_directory_sizes() {
+    let dirs = all_dir_sizes("$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt").unwrap();
+
+    assert_eq!(dirs.dirs(), [("a", 50), ("/", 150)]);
+    assert_eq!(dirs.total_space_used(), 150);
+    assert_eq!(dirs.total_size_of_dirs_at_most(100), 50);
+    assert_eq!(dirs.smallest_dir_size_bigger_than(60), 150);
+}
+
+#[test]
+fn test_cpu_and_crt() {
+    let cpu = run_program(&[Instr::NoOp, Instr::Add(3), Instr::Add(-5)]);
+
+    assert_eq!(cpu.cycle(), 5);
+    assert_eq!(cpu.register(), -1);
+    assert!(cpu.crt().is_lit(0, 0));
+    assert!(cpu.crt().is_lit(0, 4));
+    assert!(!cpu.crt().is_lit(0, 5));
+
+    let mut crt = CRT::new();
+    crt.next_cycle(0);
+
+    assert!(crt.is_lit(0, 0));
+    assert_eq!(CPU::new().register(), 1);
+}
+
+#[test]
+fn test_solvers() {
+    let (answers, timings) = DAYS[5]
+        .solve("mjqjpqmgbljsphdztnvjfqwrcgsmlb", Some(Part::One))
+        .unwrap();
+
+    assert_eq!(answers.part_one.as_deref(), Some("7"));
+    assert_eq!(answers.part_two, None);
+    assert_eq!(timings.part_two, None);
+
+    let solution: Solution<usize, usize> = Solution {
+        part_one: 7,
+        part_two: 19,
+    };
+
+    assert_eq!(solution.part_two, 19);
+}
+
+#[test]
+fn test_commands() {
+    let mut out = Vec::new();
+
+    commands::inventory(Format::Json, Some("one"), &Inputs::Stdin, &mut out).unwrap();
+
+    let json = String::from_utf8(out).unwrap();
+
+    assert!(json.contains(r#"{"elf":4,"items":3,"total":24000,"largest":9000,"smallest":7000}"#));
+
+    let result = commands::new(Path::new("."), 3, &Inputs::Stdin, &mut Vec::new());
+
+    assert!(matches!(result, Err(CommandError::Error(_))));
+}
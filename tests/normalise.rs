@@ -0,0 +1,80 @@
+use std::path::Path;
+
+use advent_of_code_2022::{
+    examples::{self, Example, EXAMPLES_DIR},
+    input::{normalise, Inputs},
+    DAYS,
+};
+
+/// Every example for `day`, along with its real input if there is one
+fn inputs_for(day: usize) -> Vec<(String, String)> {
+    let dir = Path::new(EXAMPLES_DIR);
+
+    let mut inputs: Vec<_> = examples::names(dir, day)
+        .unwrap()
+        .into_iter()
+        .map(|name| {
+            let example = Example::load(dir, day, &name).unwrap();
+
+            (format!("example `{}`", name), example.input)
+        })
+        .collect();
+
+    if let Ok(input) = Inputs::default().read(day) {
+        inputs.push(("input".to_owned(), input));
+    }
+
+    inputs
+}
+
+/// The ways an editor might mangle an input when saving it
+fn variants(input: &str) -> [(&'static str, String); 4] {
+    let crlf = input.replace('\n', "\r\n");
+
+    [
+        ("CRLF", crlf.clone()),
+        ("a BOM and CRLF", format!("\u{feff}{}", crlf)),
+        ("trailing spaces", input.replace('\n', "  \n")),
+        ("trailing tabs and CRLF", input.replace('\n', "\t\r\n")),
+    ]
+}
+
+#[test]
+fn test_every_day_solves_normalised_variants() {
+    let mut failures = Vec::new();
+
+    for day in 1..=DAYS.len() {
+        for (name, input) in inputs_for(day) {
+            let (expected, _) = DAYS[day - 1].solve(&input, None).unwrap();
+
+            for (variant, mangled) in variants(&input) {
+                let result = DAYS[day - 1].solve(&normalise(&mangled), None);
+
+                match result {
+                    Ok((answers, _))
+                        if answers.part_one == expected.part_one
+                            && answers.part_two == expected.part_two => {}
+                    Ok(_) => failures.push(format!(
+                        "day {} {} with {}: different answers",
+                        day, name, variant
+                    )),
+                    Err(e) => {
+                        failures.push(format!("day {} {} with {}: {}", day, name, variant, e))
+                    }
+                }
+            }
+        }
+    }
+
+    similar_asserts::assert_eq!(failures, Vec::<String>::new());
+}
+
+#[test]
+fn test_normalising_keeps_the_stack_diagram() {
+    let input =
+        "    [D]    \r\n[N] [C]    \r\n[Z] [M] [P]\r\n 1   2   3 \r\n\r\nmove 1 from 2 to 1\r\n";
+
+    let (answers, _) = DAYS[4].solve(&normalise(input), None).unwrap();
+
+    assert_eq!(answers.part_one.as_deref(), Some("DCP"));
+}
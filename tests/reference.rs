@@ -0,0 +1,326 @@
+//! Checks every day against its naive reference solution on random valid
+//! inputs. proptest shrinks any disagreement down to a minimal input.
+
+use std::{fmt::Write, path::Path};
+
+mod common;
+
+use advent_of_code_2022::{
+    day_01,
+    examples::{self, Example, EXAMPLES_DIR},
+    generate::generate,
+    DAYS,
+};
+use common::reference::REFERENCES;
+use proptest::{collection::vec, prelude::*, sample::Index};
+
+/// Both answers from the real solution and from the reference
+fn solve_both(day: usize, input: &str) -> [(String, String); 2] {
+    let (answers, _) = DAYS[day - 1].solve(input, None).unwrap();
+    let reference = REFERENCES[day - 1](input);
+
+    [
+        (answers.part_one.unwrap(), answers.part_two.unwrap()),
+        (reference.part_one, reference.part_two),
+    ]
+}
+
+#[test]
+fn test_references_agree_on_examples() {
+    let dir = Path::new(EXAMPLES_DIR);
+
+    for day in 1..=REFERENCES.len() {
+        for name in examples::names(dir, day).unwrap() {
+            let example = Example::load(dir, day, &name).unwrap();
+
+            let [answers, reference] = solve_both(day, &example.input);
+
+            assert_eq!(answers, reference, "day {} example `{}`", day, name);
+        }
+    }
+}
+
+fn letter() -> impl Strategy<Value = char> {
+    prop::char::range('A', 'Z')
+}
+
+/// Every letter in `letters`, in a random order
+fn shuffled(letters: impl Iterator<Item = char>) -> impl Strategy<Value = Vec<char>> {
+    Just(letters.collect::<Vec<_>>()).prop_shuffle()
+}
+
+fn day_01_input() -> impl Strategy<Value = String> {
+    vec(vec(1..100_000usize, 1..5), 0..20).prop_map(|elves| {
+        elves
+            .iter()
+            .map(|elf| elf.iter().map(|c| format!("{}\n", c)).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+fn day_02_input() -> impl Strategy<Value = String> {
+    vec((0..3u8, 0..3u8), 0..50).prop_map(|rounds| {
+        rounds
+            .iter()
+            .map(|&(a, b)| format!("{} {}\n", (b'A' + a) as char, (b'X' + b) as char))
+            .collect()
+    })
+}
+
+/// Groups of three rucksacks from the generator, as building them so their
+/// items overlap just right takes some care
+fn day_03_input() -> impl Strategy<Value = String> {
+    (0..5usize, any::<u64>()).prop_map(|(groups, seed)| {
+        let mut input = Vec::new();
+        generate(3, groups, seed, &mut input).unwrap();
+
+        String::from_utf8(input).unwrap()
+    })
+}
+
+fn day_04_input() -> impl Strategy<Value = String> {
+    let range = (1..100usize, 0..20usize).prop_map(|(start, len)| (start, start + len));
+
+    vec((range.clone(), range), 0..50).prop_map(|pairs| {
+        pairs
+            .iter()
+            .map(|((a, b), (c, d))| format!("{}-{},{}-{}\n", a, b, c, d))
+            .collect()
+    })
+}
+
+/// Up to nine stacks of at most four crates, and moves which are all possible
+/// when made in order
+fn day_05_input() -> impl Strategy<Value = String> {
+    let stacks = vec(vec(letter(), 0..5), 1..10);
+    let moves = vec((any::<Index>(), any::<Index>(), any::<Index>()), 0..20);
+
+    (stacks, moves).prop_map(|(stacks, moves)| {
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut input = String::new();
+
+        for level in (0..height).rev() {
+            let row: Vec<String> = stacks
+                .iter()
+                .map(|s| match s.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_owned(),
+                })
+                .collect();
+
+            let _ = writeln!(input, "{}", row.join(" "));
+        }
+
+        let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect();
+        let _ = writeln!(input, "{}\n", numbers.join(" "));
+
+        let mut sizes: Vec<usize> = stacks.iter().map(Vec::len).collect();
+
+        for (from, to, num) in moves {
+            let not_empty: Vec<usize> = (0..sizes.len()).filter(|&i| sizes[i] > 0).collect();
+
+            if not_empty.is_empty() || sizes.len() == 1 {
+                break;
+            }
+
+            let from = not_empty[from.index(not_empty.len())];
+            let to = (from + 1 + to.index(sizes.len() - 1)) % sizes.len();
+            let num = 1 + num.index(sizes[from]);
+
+            sizes[from] -= num;
+            sizes[to] += num;
+
+            let _ = writeln!(input, "move {} from {} to {}", num, from + 1, to + 1);
+        }
+
+        input
+    })
+}
+
+/// Some noise from a few letters, then fourteen different letters so there's
+/// always a start-of-message marker
+fn day_06_input() -> impl Strategy<Value = String> {
+    let noise = vec(prop::char::range('a', 'e'), 0..40);
+
+    (noise, shuffled('a'..='z')).prop_map(|(noise, items)| {
+        noise
+            .into_iter()
+            .chain(items.into_iter().take(14))
+            .collect()
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Dir {
+    files: Vec<usize>,
+    dirs: Vec<Dir>,
+}
+
+impl Dir {
+    fn used(&self) -> usize {
+        self.files.iter().sum::<usize>() + self.dirs.iter().map(Dir::used).sum::<usize>()
+    }
+
+    /// Lists this directory then visits each of its subdirectories
+    fn explore(&self, output: &mut String) {
+        output.push_str("$ ls\n");
+
+        for i in 0..self.dirs.len() {
+            let _ = writeln!(output, "dir d{}", i);
+        }
+
+        for (i, size) in self.files.iter().enumerate() {
+            let _ = writeln!(output, "{} f{}.txt", size, i);
+        }
+
+        for (i, dir) in self.dirs.iter().enumerate() {
+            let _ = writeln!(output, "$ cd d{}", i);
+            dir.explore(output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+}
+
+/// A terminal session exploring a random filesystem of at most 70000000
+fn day_07_input() -> impl Strategy<Value = String> {
+    let size = prop_oneof![1..100_000usize, 1..3_000_000usize];
+    let files = vec(size, 0..4);
+
+    let leaf = files.clone().prop_map(|files| Dir {
+        files,
+        dirs: Vec::new(),
+    });
+
+    let root = leaf.prop_recursive(4, 30, 4, move |dir| {
+        (files.clone(), vec(dir, 0..4)).prop_map(|(files, dirs)| Dir { files, dirs })
+    });
+
+    root.prop_filter("the disk is only 70000000", |root| {
+        root.used() <= 70_000_000
+    })
+    .prop_map(|root| {
+        let mut output = String::from("$ cd /\n");
+        root.explore(&mut output);
+        output
+    })
+}
+
+fn day_08_input() -> impl Strategy<Value = String> {
+    (1..10usize, 1..10usize)
+        .prop_flat_map(|(width, height)| (Just(width), vec(0..10u8, width * height)))
+        .prop_map(|(width, heights)| {
+            heights
+                .chunks(width)
+                .map(|row| row.iter().map(|h| format!("{}", h)).collect::<String>() + "\n")
+                .collect()
+        })
+}
+
+fn day_09_input() -> impl Strategy<Value = String> {
+    let dir = prop::sample::select(vec!['U', 'D', 'L', 'R']);
+
+    vec((dir, 1..10usize), 0..30).prop_map(|moves| {
+        moves
+            .iter()
+            .map(|(dir, steps)| format!("{} {}\n", dir, steps))
+            .collect()
+    })
+}
+
+/// A program that runs for at most the 240 cycles the screen has room for
+fn day_10_input() -> impl Strategy<Value = String> {
+    let instruction = prop::option::of(-10..10isize);
+
+    vec(instruction, 0..150).prop_map(|program| {
+        let mut input = String::new();
+        let mut cycles = 0;
+
+        for instruction in program {
+            match instruction {
+                Some(x) if cycles + 2 <= 240 => {
+                    let _ = writeln!(input, "addx {}", x);
+                    cycles += 2;
+                }
+                _ if cycles < 240 => {
+                    input.push_str("noop\n");
+                    cycles += 1;
+                }
+                _ => break,
+            }
+        }
+
+        input
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_day_01(input in day_01_input()) {
+        let [answers, reference] = solve_both(1, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_01_top_n(calories in vec(0..100usize, 0..50), n in 0..60usize) {
+        let mut sorted = calories.clone();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        sorted.truncate(n);
+
+        prop_assert_eq!(day_01::top_n(calories, n), sorted);
+    }
+
+    #[test]
+    fn test_day_02(input in day_02_input()) {
+        let [answers, reference] = solve_both(2, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_03(input in day_03_input()) {
+        let [answers, reference] = solve_both(3, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_04(input in day_04_input()) {
+        let [answers, reference] = solve_both(4, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_05(input in day_05_input()) {
+        let [answers, reference] = solve_both(5, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_06(input in day_06_input()) {
+        let [answers, reference] = solve_both(6, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_07(input in day_07_input()) {
+        let [answers, reference] = solve_both(7, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_08(input in day_08_input()) {
+        let [answers, reference] = solve_both(8, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_09(input in day_09_input()) {
+        let [answers, reference] = solve_both(9, &input);
+        prop_assert_eq!(answers, reference);
+    }
+
+    #[test]
+    fn test_day_10(input in day_10_input()) {
+        let [answers, reference] = solve_both(10, &input);
+        prop_assert_eq!(answers, reference);
+    }
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use std::{fs, io, path::Path, process::Command};
+
+use advent_of_code_2022::{scaffold::scaffold, DAYS};
+use common::temp_dir;
+
+/// Copies a file or a whole directory
+fn copy(from: &Path, to: &Path) -> io::Result<()> {
+    if from.is_file() {
+        return fs::copy(from, to).map(|_| ());
+    }
+
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+
+        copy(&entry.path(), &to.join(entry.file_name()))?;
+    }
+
+    Ok(())
+}
+
+/// Scaffolds the next day in a copy of the crate, then checks the copy still
+/// builds with the new day registered, tests and all
+#[test]
+fn test_new_day_compiles() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let crate_copy = temp_dir("scaffold-compiles");
+
+    for name in [
+        "Cargo.toml",
+        "Cargo.lock",
+        "src",
+        "templates",
+        "rules",
+        "examples",
+    ] {
+        if root.join(name).exists() {
+            copy(&root.join(name), &crate_copy.join(name)).unwrap();
+        }
+    }
+
+    let day = DAYS.len() + 1;
+
+    scaffold(&crate_copy, &crate_copy.join("inputs"), day, DAYS.len()).unwrap();
+
+    let lib_rs = fs::read_to_string(crate_copy.join("src").join("lib.rs")).unwrap();
+
+    assert!(lib_rs.contains(&format!("pub mod day_{:02};", day)));
+    assert!(lib_rs.contains(&format!("&day_{:02}::Day{:02},", day, day)));
+
+    // Shares a target directory between runs so only the crate itself is
+    // rebuilt each time
+    let output = Command::new(env!("CARGO"))
+        .args(["check", "--offline", "--all-targets", "--quiet"])
+        .current_dir(&crate_copy)
+        .env(
+            "CARGO_TARGET_DIR",
+            root.join("target").join("scaffold-check"),
+        )
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    fs::remove_dir_all(crate_copy).unwrap();
+}
@@ -0,0 +1,186 @@
+mod common;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Output,
+};
+
+use common::{run, stderr, stdout, temp_dir, MockServer};
+
+/// An inputs directory holding the real day 6 input and a session token
+fn inputs_dir(name: &str) -> PathBuf {
+    let dir = temp_dir(name);
+
+    fs::copy("inputs/day_06.txt", dir.join("day_06.txt")).unwrap();
+    fs::write(dir.join(".session"), "abc123").unwrap();
+
+    dir
+}
+
+fn submit(server: &MockServer, dir: &Path, extra: &[&str]) -> Output {
+    let mut args = vec![
+        "submit",
+        "6",
+        "1",
+        "--inputs",
+        dir.to_str().unwrap(),
+        "--base-url",
+        &server.url,
+        "--delay",
+        "0",
+    ];
+
+    args.extend(extra);
+
+    run(&args)
+}
+
+fn answers(dir: &Path) -> String {
+    fs::read_to_string(dir.join("answers.txt")).unwrap_or_default()
+}
+
+fn page(message: &str) -> String {
+    format!(
+        "<html><body><main><article><p>{}</p></article></main></body></html>",
+        message
+    )
+}
+
+#[test]
+fn test_correct_answer_is_recorded() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page("That's the right answer!  You are one gold star closer."),
+        )
+    });
+    let dir = inputs_dir("submit-correct");
+
+    fs::write(dir.join("answers.txt"), "# Answers\n5.1: GFTNRBZPF").unwrap();
+
+    let output = submit(&server, &dir, &[]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("`1198` is the right answer"));
+    assert_eq!(answers(&dir), "# Answers\n5.1: GFTNRBZPF\n6.1: 1198\n");
+
+    let requests = server.requests();
+
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/6/answer");
+    assert_eq!(requests[0].body, "level=1&answer=1198");
+    assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+
+    let output = submit(&server, &dir, &[]);
+
+    assert!(stderr(&output).contains("day 6 part 1 is already solved"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn test_wrong_answers_are_recorded_and_refused() {
+    let cases = [
+        (
+            "too-high",
+            "your answer is too high",
+            "100",
+            "6.1 too high: 100\n",
+            "`100` is too high",
+        ),
+        (
+            "too-low",
+            "your answer is too low",
+            "5000",
+            "6.1 too low: 5000\n",
+            "`5000` is too low",
+        ),
+        (
+            "wrong",
+            "",
+            "GFTNRBZPF",
+            "6.1 wrong: GFTNRBZPF\n",
+            "`GFTNRBZPF` isn't the right answer",
+        ),
+    ];
+
+    for (name, detail, answer, recorded, message) in cases {
+        let server = MockServer::start(move |_| {
+            (
+                200,
+                page(&format!("That's not the right answer; {}.", detail)),
+            )
+        });
+        let dir = inputs_dir(&format!("submit-{}", name));
+
+        let output = submit(&server, &dir, &["--answer", answer]);
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stdout(&output).contains(message), "{}", stdout(&output));
+        assert_eq!(answers(&dir), recorded);
+
+        let output = submit(&server, &dir, &["--answer", answer]);
+
+        assert!(stderr(&output).contains("was already submitted"));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
+
+#[test]
+fn test_answers_beyond_a_known_bound_are_refused() {
+    let server = MockServer::start(|_| (200, String::new()));
+    let dir = inputs_dir("submit-bound");
+
+    fs::write(dir.join("answers.txt"), "6.1 too high: 1000\n").unwrap();
+
+    let output = submit(&server, &dir, &[]);
+
+    assert!(stderr(&output).contains("`1198` can't be right, `1000` was already too high"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_wait() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+        )
+    });
+    let dir = inputs_dir("submit-wait");
+
+    let output = submit(&server, &dir, &[]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("wait 1m 5s before trying again"));
+    assert_eq!(answers(&dir), "");
+}
+
+#[test]
+fn test_already_solved() {
+    let server = MockServer::start(|_| {
+        (
+            200,
+            page("You don't seem to be solving the right level.  Did you already complete it?"),
+        )
+    });
+    let dir = inputs_dir("submit-solved");
+
+    let output = submit(&server, &dir, &[]);
+
+    assert!(stdout(&output).contains("already solved"));
+    assert_eq!(answers(&dir), "");
+}
+
+#[test]
+fn test_unrecognised_response() {
+    let server = MockServer::start(|_| (200, page("Something unexpected")));
+    let dir = inputs_dir("submit-unrecognised");
+
+    let output = submit(&server, &dir, &[]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("didn't say whether the answer was right"));
+    assert_eq!(answers(&dir), "");
+}
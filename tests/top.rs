@@ -0,0 +1,51 @@
+mod common;
+
+use std::{fs, io::Write};
+
+use advent_of_code_2022::generate::generate;
+use common::{run, run_with_stdin, stderr, stdout, temp_dir};
+use flate2::{write::GzEncoder, Compression};
+
+fn gzipped(input: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    encoder.write_all(input).unwrap();
+
+    encoder.finish().unwrap()
+}
+
+fn elves() -> Vec<u8> {
+    let mut input = Vec::new();
+    generate(1, 2000, 7, &mut input).unwrap();
+
+    input
+}
+
+#[test]
+fn test_gzipped_stdin_with_and_without_jobs() {
+    let plain = run_with_stdin(&["1", "-i", "-", "--top", "3"], &elves());
+
+    assert!(plain.status.success(), "{}", stderr(&plain));
+
+    for args in [&["--top", "3"][..], &["--top", "3", "-j", "4"]] {
+        let output = run_with_stdin(&[&["1", "-i", "-"], args].concat(), &gzipped(&elves()));
+
+        assert!(output.status.success(), "{:?}: {}", args, stderr(&output));
+        assert_eq!(stdout(&output), stdout(&plain), "{:?}", args);
+    }
+}
+
+#[test]
+fn test_gzipped_file_with_jobs() {
+    let dir = temp_dir("top-gzipped");
+    fs::write(dir.join("day_01.txt"), gzipped(&elves())).unwrap();
+
+    let inputs = dir.to_str().unwrap();
+
+    let streamed = run(&["1", "-i", inputs, "--top", "5"]);
+    let split = run(&["1", "-i", inputs, "--top", "5", "--jobs", "3"]);
+
+    assert!(split.status.success(), "{}", stderr(&split));
+    assert_eq!(stdout(&split), stdout(&streamed));
+
+    fs::remove_dir_all(dir).unwrap();
+}